@@ -0,0 +1,335 @@
+//! Code generation for typed Rust event and call bindings from Ethereum
+//! contract ABIs.
+//!
+//! Given a contract's JSON ABI (as emitted by `solc` or similar tools), this
+//! module generates one Rust struct per event and per call handler, with
+//! fields already decoded from the AssemblyScript Ethereum event object into
+//! strongly typed `BigInt`s, address bytes, indexed topics and tuples,
+//! instead of requiring mapping authors to hand-write FFI decoding. This is
+//! the same general approach as ethers-rs's `Abigen`: overloaded event/call
+//! names that collide after normalization get a deterministic numbered
+//! suffix (`Transfer1`, `Transfer2`, ...).
+
+use anyhow::{Context as _, Result};
+use serde::Deserialize;
+use std::{collections::HashMap, fmt::Write as _, fs, path::Path};
+
+/// A parsed contract ABI.
+pub struct Abi {
+    items: Vec<AbiItem>,
+}
+
+impl Abi {
+    /// Reads and parses a contract ABI from a JSON file.
+    pub fn read(path: &Path) -> Result<Self> {
+        let contents = fs::read(path)
+            .with_context(|| format!("error reading ABI file '{}'", path.display()))?;
+        let items = serde_json::from_slice(&contents)
+            .with_context(|| format!("error parsing ABI file '{}'", path.display()))?;
+
+        Ok(Self { items })
+    }
+
+    /// Generates the Rust source for typed event and call bindings for a
+    /// contract with the specified name.
+    pub fn generate(&self, contract_name: &str) -> String {
+        let mut source = format!(
+            "//! Generated bindings for the `{}` contract ABI.\n\
+             //!\n\
+             //! This file is generated by `cargo subgraph abigen`. Do not edit by hand.\n\n\
+             use subgraph::BigInt;\n\n",
+            contract_name,
+        );
+
+        let events = self
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                AbiItem::Event(event) => Some(event),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        for (name, event) in deduplicate(events.iter().map(|event| event.name.as_str())) {
+            write_event(&mut source, &name, events[event]);
+        }
+
+        let functions = self
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                AbiItem::Function(function) => Some(function),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        for (name, function) in deduplicate(functions.iter().map(|function| function.name.as_str()))
+        {
+            write_call(&mut source, &name, functions[function]);
+        }
+
+        source
+    }
+}
+
+/// Assigns a deterministic, unique identifier to each (possibly repeated)
+/// name, numbering overloads in declaration order (`Transfer1`, `Transfer2`,
+/// ...) and leaving non-overloaded names untouched.
+fn deduplicate<'a>(names: impl Iterator<Item = &'a str>) -> Vec<(String, usize)> {
+    let names = names.collect::<Vec<_>>();
+    let mut counts = HashMap::new();
+    for name in &names {
+        *counts.entry(*name).or_insert(0) += 1;
+    }
+
+    let mut seen = HashMap::new();
+    names
+        .iter()
+        .enumerate()
+        .map(|(index, name)| {
+            let unique_name = if counts[name] > 1 {
+                let n = seen.entry(*name).or_insert(0);
+                *n += 1;
+                format!("{}{}", pascal_case(name), n)
+            } else {
+                pascal_case(name)
+            };
+            (unique_name, index)
+        })
+        .collect()
+}
+
+fn write_event(source: &mut String, name: &str, event: &EventItem) {
+    let _ = writeln!(source, "/// The `{}` event.", event.name);
+    let _ = writeln!(source, "pub struct {}Event {{", name);
+    for param in &event.inputs {
+        let _ = writeln!(
+            source,
+            "    pub {}: {},",
+            rust_field_name(&param.name),
+            describe_type(&param.ty).rust_type,
+        );
+    }
+    let _ = writeln!(source, "}}\n");
+
+    let _ = writeln!(source, "impl {}Event {{", name);
+    let _ = writeln!(
+        source,
+        "    /// Decodes a `{}Event` from the event's ABI-ordered parameters.",
+        name,
+    );
+    let _ = writeln!(
+        source,
+        "    pub fn decode(event: subgraph::ethereum::Event) -> Self {{",
+    );
+    let _ = writeln!(source, "        let mut params = event.parameters.into_iter();");
+    let _ = writeln!(source, "        Self {{");
+    for param in &event.inputs {
+        let _ = writeln!(
+            source,
+            "            {}: {{ let value = params.next().expect(\"missing event parameter\").value; {} }},",
+            rust_field_name(&param.name),
+            describe_type(&param.ty).decode_expr,
+        );
+    }
+    let _ = writeln!(source, "        }}");
+    let _ = writeln!(source, "    }}");
+    let _ = writeln!(source, "}}\n");
+}
+
+fn write_call(source: &mut String, name: &str, function: &FunctionItem) {
+    let _ = writeln!(source, "/// Parameters for the `{}` call.", function.name);
+    let _ = writeln!(source, "pub struct {}Call {{", name);
+    for param in &function.inputs {
+        let _ = writeln!(
+            source,
+            "    pub {}: {},",
+            rust_field_name(&param.name),
+            describe_type(&param.ty).rust_type,
+        );
+    }
+    let _ = writeln!(source, "}}\n");
+
+    let _ = writeln!(source, "impl {}Call {{", name);
+    let _ = writeln!(
+        source,
+        "    /// Decodes a `{}Call` from the call's ABI-ordered inputs.",
+        name,
+    );
+    let _ = writeln!(
+        source,
+        "    pub fn decode(call: subgraph::ethereum::Call) -> Self {{",
+    );
+    let _ = writeln!(source, "        let mut params = call.inputs.into_iter();");
+    let _ = writeln!(source, "        Self {{");
+    for param in &function.inputs {
+        let _ = writeln!(
+            source,
+            "            {}: {{ let value = params.next().expect(\"missing call parameter\").value; {} }},",
+            rust_field_name(&param.name),
+            describe_type(&param.ty).decode_expr,
+        );
+    }
+    let _ = writeln!(source, "        }}");
+    let _ = writeln!(source, "    }}");
+    let _ = writeln!(source, "}}\n");
+}
+
+/// How a Solidity ABI type decodes: the Rust type used to represent its
+/// value, and an expression (referencing a local `value: subgraph::ethereum::Value`)
+/// that decodes it.
+struct TypeInfo {
+    rust_type: String,
+    decode_expr: String,
+}
+
+/// Maps a Solidity ABI type to the Rust type used to represent its decoded
+/// value in a mapping handler, and how to decode it from a
+/// `subgraph::ethereum::Value`.
+///
+/// Array types (`T[]`/`T[N]`, including multiple dimensions) recurse on
+/// their element type and decode to a `Vec` of it. `tuple` components
+/// aren't individually named (the ABI's `components` array isn't tracked
+/// here); a tuple decodes to its raw `Vec<subgraph::ethereum::Value>`
+/// elements instead of a generated struct.
+fn describe_type(ty: &str) -> TypeInfo {
+    if let Some(element_ty) = strip_array_suffix(ty) {
+        let element = describe_type(element_ty);
+        return TypeInfo {
+            rust_type: format!("Vec<{}>", element.rust_type),
+            decode_expr: format!(
+                "value.into_array().into_iter().map(|value| {}).collect()",
+                element.decode_expr,
+            ),
+        };
+    }
+
+    let (rust_type, decode_expr) = match ty {
+        "address" => ("[u8; 20]", "value.into_address()"),
+        "bool" => ("bool", "value.into_bool()"),
+        "string" => ("String", "value.into_string()"),
+        "bytes" => ("Vec<u8>", "value.into_bytes()"),
+        "tuple" => ("Vec<subgraph::ethereum::Value>", "value.into_array()"),
+        t if t.starts_with("uint") || t.starts_with("int") => ("BigInt", "value.into_big_int()"),
+        t if t.starts_with("bytes") => ("Vec<u8>", "value.into_bytes()"),
+        _ => ("Vec<u8>", "value.into_bytes()"),
+    };
+    TypeInfo {
+        rust_type: rust_type.to_owned(),
+        decode_expr: decode_expr.to_owned(),
+    }
+}
+
+/// Strips one trailing `[]`/`[N]` array suffix from a Solidity ABI type,
+/// returning the element type.
+fn strip_array_suffix(ty: &str) -> Option<&str> {
+    let trimmed = ty.strip_suffix(']')?;
+    let open = trimmed.rfind('[')?;
+    Some(&ty[..open])
+}
+
+fn rust_field_name(name: &str) -> String {
+    if name.is_empty() {
+        "value".to_owned()
+    } else {
+        name.to_owned()
+    }
+}
+
+fn pascal_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut capitalize = true;
+    for c in name.chars() {
+        if c == '_' {
+            capitalize = true;
+            continue;
+        }
+        if capitalize {
+            out.extend(c.to_uppercase());
+            capitalize = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum AbiItem {
+    Event(EventItem),
+    Function(FunctionItem),
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize)]
+struct EventItem {
+    name: String,
+    #[serde(default)]
+    inputs: Vec<Param>,
+}
+
+#[derive(Deserialize)]
+struct FunctionItem {
+    name: String,
+    #[serde(default)]
+    inputs: Vec<Param>,
+}
+
+#[derive(Deserialize)]
+struct Param {
+    #[serde(default)]
+    name: String,
+    #[serde(rename = "type")]
+    ty: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deduplicates_overloaded_names() {
+        let names = deduplicate(["Transfer", "Approval", "Transfer"].into_iter());
+        assert_eq!(names[0].0, "Transfer1");
+        assert_eq!(names[1].0, "Approval");
+        assert_eq!(names[2].0, "Transfer2");
+    }
+
+    #[test]
+    fn maps_solidity_types() {
+        assert_eq!(describe_type("uint256").rust_type, "BigInt");
+        assert_eq!(describe_type("int128").rust_type, "BigInt");
+        assert_eq!(describe_type("address").rust_type, "[u8; 20]");
+        assert_eq!(describe_type("bool").rust_type, "bool");
+        assert_eq!(describe_type("bytes32").rust_type, "Vec<u8>");
+    }
+
+    #[test]
+    fn maps_solidity_array_types() {
+        assert_eq!(describe_type("uint256[]").rust_type, "Vec<BigInt>");
+        assert_eq!(
+            describe_type("uint256[]").decode_expr,
+            "value.into_array().into_iter().map(|value| value.into_big_int()).collect()",
+        );
+        assert_eq!(describe_type("address[3]").rust_type, "Vec<[u8; 20]>");
+        assert_eq!(describe_type("uint256[][3]").rust_type, "Vec<Vec<BigInt>>");
+    }
+
+    #[test]
+    fn maps_solidity_tuple_types() {
+        assert_eq!(
+            describe_type("tuple").rust_type,
+            "Vec<subgraph::ethereum::Value>",
+        );
+        assert_eq!(
+            describe_type("tuple[]").rust_type,
+            "Vec<Vec<subgraph::ethereum::Value>>",
+        );
+    }
+
+    #[test]
+    fn converts_to_pascal_case() {
+        assert_eq!(pascal_case("transfer_from"), "TransferFrom");
+        assert_eq!(pascal_case("Transfer"), "Transfer");
+    }
+}
@@ -1,8 +1,9 @@
 //! The Graph JSONRPC client API.
 
-use crate::api::{ipfs::CidV0, jsonrpc};
-use anyhow::{anyhow, Context as _, Result};
+use crate::api::{ipfs::Cid, jsonrpc};
+use anyhow::{anyhow, bail, Context as _, Result};
 use serde::{Deserialize, Serialize};
+use std::{thread, time::Duration};
 use url::Url;
 
 pub struct Client {
@@ -16,19 +17,82 @@ impl Client {
             inner: jsonrpc::Client::new(url),
         }
     }
+}
+
+/// A client that blocks until a request is acknowledged by the Graph node,
+/// optionally waiting for the resulting deployment to finish syncing.
+///
+/// This mirrors the "send and confirm" semantics of Solana's
+/// `send_and_confirm_message`: a request is (re-)submitted with fresh state
+/// on transient failure, and `deploy_and_confirm` doesn't return until the
+/// deployment reaches the requested commitment (here, an indexed block
+/// height) or the retry budget is exhausted.
+pub trait SyncClient {
+    /// Creates a new subgraph with the specified name, retrying on transient
+    /// failures.
+    fn create(&self, name: &str) -> Result<Subgraph>;
+
+    /// Deploys a subgraph and blocks until the Graph node reports that it has
+    /// finished (or caught up to) indexing, according to `confirm`.
+    fn deploy_and_confirm(&self, name: &str, cid: &Cid, confirm: Confirm) -> Result<Routes>;
+}
+
+/// A client that submits requests to the Graph node without waiting for them
+/// to be acted on.
+pub trait AsyncClient {
+    /// Deploys a subgraph by name and IPFS CID of the subgraph descriptor,
+    /// returning as soon as the Graph node has accepted the deployment.
+    fn deploy(&self, name: &str, cid: &Cid) -> Result<Routes>;
+}
 
-    /// Creates a new subgraph with the specified name.
-    pub fn create(&self, name: &str) -> Result<Subgraph> {
-        self.inner.execute("subgraph_create", Create { name })
+impl SyncClient for Client {
+    fn create(&self, name: &str) -> Result<Subgraph> {
+        retry(Confirm::default().retries, Confirm::default().backoff, || {
+            self.inner.execute("subgraph_create", Create { name })
+        })
     }
 
-    /// Deploys a subgraph by name and IPFS CID of the subgraph descriptor.
-    pub fn deploy(&self, name: &str, cid: CidV0) -> Result<Routes> {
+    fn deploy_and_confirm(&self, name: &str, cid: &Cid, confirm: Confirm) -> Result<Routes> {
+        let routes = retry(confirm.retries, confirm.backoff, || {
+            AsyncClient::deploy(self, name, cid)
+        })?;
+
+        if let Some(target_block) = confirm.target_block {
+            let mut backoff = confirm.backoff;
+            for attempt in 0..confirm.retries {
+                let status = self.indexing_status(name)?;
+                if status.failed {
+                    bail!("deployment '{}' failed to sync: {}", name, status.synced_to);
+                }
+                if status.synced_to >= target_block {
+                    return Ok(routes);
+                }
+
+                if attempt + 1 == confirm.retries {
+                    bail!(
+                        "deployment '{}' did not reach block {} after {} attempts (synced to {})",
+                        name,
+                        target_block,
+                        confirm.retries,
+                        status.synced_to,
+                    );
+                }
+                thread::sleep(backoff);
+                backoff *= 2;
+            }
+        }
+
+        Ok(routes)
+    }
+}
+
+impl AsyncClient for Client {
+    fn deploy(&self, name: &str, cid: &Cid) -> Result<Routes> {
         let routes = self.inner.execute::<_, RawRoutes>(
             "subgraph_deploy",
             Deploy {
                 name,
-                ipfs_hash: cid,
+                ipfs_hash: cid.clone(),
             },
         )?;
 
@@ -36,6 +100,69 @@ impl Client {
     }
 }
 
+impl Client {
+    fn indexing_status(&self, name: &str) -> Result<IndexingStatus> {
+        self.inner
+            .execute("subgraph_indexingStatus", IndexingStatusQuery { name })
+    }
+}
+
+fn retry<T>(attempts: u32, mut backoff: Duration, mut f: impl FnMut() -> Result<T>) -> Result<T> {
+    let mut last_err = None;
+    for attempt in 0..attempts.max(1) {
+        match f() {
+            Ok(value) => return Ok(value),
+            Err(err) => {
+                last_err = Some(err);
+                if attempt + 1 < attempts {
+                    thread::sleep(backoff);
+                    backoff *= 2;
+                }
+            }
+        }
+    }
+
+    Err(last_err.expect("attempts is always at least 1"))
+}
+
+/// Confirmation parameters for [`SyncClient::deploy_and_confirm`].
+#[derive(Clone, Copy)]
+pub struct Confirm {
+    /// The block number that the deployment must reach before
+    /// `deploy_and_confirm` returns. When `None`, only the initial deploy
+    /// request is confirmed and syncing progress is not waited on.
+    pub target_block: Option<u64>,
+    /// The maximum number of attempts for both the initial request and each
+    /// status poll.
+    pub retries: u32,
+    /// The initial delay between attempts. Doubles after every attempt
+    /// (exponential backoff).
+    pub backoff: Duration,
+}
+
+impl Default for Confirm {
+    fn default() -> Self {
+        Self {
+            target_block: None,
+            retries: 10,
+            backoff: Duration::from_millis(500),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct IndexingStatusQuery<'a> {
+    name: &'a str,
+}
+
+#[derive(Deserialize)]
+struct IndexingStatus {
+    #[serde(rename = "syncedTo")]
+    synced_to: u64,
+    #[serde(default)]
+    failed: bool,
+}
+
 #[derive(Serialize)]
 struct Create<'a> {
     name: &'a str,
@@ -51,7 +178,7 @@ pub struct Subgraph {
 #[derive(Serialize)]
 struct Deploy<'a> {
     name: &'a str,
-    ipfs_hash: CidV0,
+    ipfs_hash: Cid,
 }
 
 #[derive(Deserialize)]
@@ -146,7 +273,7 @@ mod tests {
         assert_eq!(
             serde_json::to_value(Deploy {
                 name: "my/subgraph",
-                ipfs_hash: CidV0([0; 32]),
+                ipfs_hash: Cid::v0([0; 32]),
             })
             .unwrap(),
             json!({
@@ -182,7 +309,7 @@ mod tests {
         println!("Created my/subgraph at 0x{}", subgraph.id);
     }
 
-    fn add_and_pin_test_file(name: impl AsRef<Path>) -> CidV0 {
+    fn add_and_pin_test_file(name: impl AsRef<Path>) -> Cid {
         ipfs::Client::new(Url::parse("http://localhost:5001").unwrap())
             .add_and_pin(
                 &Path::new(env!("CARGO_MANIFEST_DIR"))
@@ -202,7 +329,7 @@ mod tests {
         add_and_pin_test_file("MyContract.abi");
         add_and_pin_test_file("mapping.wasm");
         let manifest = add_and_pin_test_file("subgraph.linked.yaml");
-        let routes = client.deploy("my/subgraph", manifest).unwrap();
+        let routes = client.deploy("my/subgraph", &manifest).unwrap();
 
         println!("Deployed my/subgraph at {}", routes.playground);
     }
@@ -7,85 +7,307 @@ use serde::{
     ser::Serializer,
     Deserialize, Serialize,
 };
+use sha2::{Digest, Sha256};
 use std::{
     fmt::{self, Debug, Display, Formatter},
+    fs,
     path::{Path, PathBuf},
     str,
 };
 use url::Url;
 
-/// CID v0.
-///
-/// The bytes are the Sha-256 hash of the data being identified.
+/// `dag-pb` multicodec, used by CIDv0 and by most CIDv1s pointing at UnixFS
+/// file/directory nodes.
+const DAG_PB: u64 = 0x70;
+
+/// `sha2-256` multihash function code.
+const SHA2_256: u64 = 0x12;
+
+/// A CID version, distinguishing the legacy bare base58btc CIDv0 form from
+/// the self-describing multicodec/multibase CIDv1 form.
 #[derive(Copy, Clone, Eq, PartialEq)]
-pub struct CidV0(pub [u8; 32]);
+enum Version {
+    V0,
+    V1,
+}
+
+/// An IPFS Content Identifier.
+///
+/// Carries a version, a content multicodec, a multihash function code, and
+/// the digest itself, so it can represent both the legacy CIDv0 form (a
+/// bare base58btc `Qm...` string, implicitly dag-pb + sha2-256) and CIDv1
+/// multibase strings such as the base32 `b...` form that `graph-node` and
+/// modern `ipfs` nodes increasingly return.
+///
+/// `version` is purely a representation detail: two `Cid`s naming the same
+/// content encode the same `codec`/`hash_code`/`digest` whether one of them
+/// happens to be written out as CIDv0 or CIDv1, so equality ignores it.
+#[derive(Clone)]
+pub struct Cid {
+    version: Version,
+    codec: u64,
+    hash_code: u64,
+    digest: Vec<u8>,
+}
+
+impl Eq for Cid {}
+
+impl PartialEq for Cid {
+    fn eq(&self, other: &Self) -> bool {
+        self.codec == other.codec && self.hash_code == other.hash_code && self.digest == other.digest
+    }
+}
+
+impl Cid {
+    /// Creates a CIDv0 from a raw 32-byte SHA-256 digest (the `Qm...`
+    /// base58btc form, with an implicit dag-pb codec).
+    pub fn v0(digest: [u8; 32]) -> Self {
+        Self {
+            version: Version::V0,
+            codec: DAG_PB,
+            hash_code: SHA2_256,
+            digest: digest.to_vec(),
+        }
+    }
+
+    /// Parses a CID in either its legacy bare base58btc CIDv0 form
+    /// (`Qm...`) or a multibase-prefixed CIDv1 string. At minimum, the
+    /// base32 (`b...`) multibase is supported, since that's what `ipfs`
+    /// nodes emit by default for CIDv1.
+    pub fn parse(s: &str) -> Result<Self> {
+        if let Some(body) = s.strip_prefix('b') {
+            return Self::parse_v1(&decode_base32(body)?);
+        }
 
-impl CidV0 {
-    /// Parses the base58 string and returns a CID.
+        Self::from_base58(s)
+    }
+
+    /// Parses the legacy bare base58btc CIDv0 form (`Qm...`).
     pub fn from_base58(s: &str) -> Result<Self> {
-        ensure!(&s[..2] == "Qm", "missing CID v0 0x1220 prefix");
+        ensure!(s.get(..2) == Some("Qm"), "missing CID v0 0x1220 prefix");
         let mut buf = [0u8; 34];
         bs58::decode(s).into(&mut buf)?;
+        ensure!(buf[..2] == [0x12, 0x20], "missing CID v0 0x1220 prefix");
+
         let mut digest = [0u8; 32];
         digest.copy_from_slice(&buf[2..]);
-        Ok(Self(digest))
+        Ok(Self::v0(digest))
+    }
+
+    /// Parses a CIDv1's decoded multibase body: a varint version, followed
+    /// by a varint codec, then a multihash (varint function code, varint
+    /// digest length, and the digest itself).
+    fn parse_v1(mut bytes: &[u8]) -> Result<Self> {
+        let version = read_varint(&mut bytes)?;
+        ensure!(version == 1, "unsupported CID version {}", version);
+
+        let codec = read_varint(&mut bytes)?;
+        let hash_code = read_varint(&mut bytes)?;
+        let len = read_varint(&mut bytes)?;
+        ensure!(
+            bytes.len() as u64 == len,
+            "multihash digest length does not match its declared length",
+        );
+
+        Ok(Self {
+            version: Version::V1,
+            codec,
+            hash_code,
+            digest: bytes.to_vec(),
+        })
     }
 
-    /// Returns the base58 representation of the CID.
+    /// Returns the base58btc `Qm...` representation of this CID.
+    ///
+    /// Like the legacy `CidV0` type this one replaces, this only supports
+    /// the dag-pb/sha2-256 CIDv0 encoding.
     pub fn as_base58(&self) -> String {
+        assert!(
+            self.codec == DAG_PB && self.hash_code == SHA2_256 && self.digest.len() == 32,
+            "CID is not representable as a CID v0",
+        );
+
         let mut buf = [0u8; 34];
         buf[..2].copy_from_slice(b"\x12\x20");
-        buf[2..].copy_from_slice(&self.0);
+        buf[2..].copy_from_slice(&self.digest);
         bs58::encode(buf).into_string()
     }
+
+    /// Returns the CIDv1 base32 `b...` representation of this CID.
+    fn as_base32(&self) -> String {
+        let mut bytes = Vec::new();
+        write_varint(1, &mut bytes);
+        write_varint(self.codec, &mut bytes);
+        write_varint(self.hash_code, &mut bytes);
+        write_varint(self.digest.len() as u64, &mut bytes);
+        bytes.extend_from_slice(&self.digest);
+
+        format!("b{}", encode_base32(&bytes))
+    }
 }
 
-impl Debug for CidV0 {
+impl Debug for Cid {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        f.write_str(&self.as_base58())
+        Display::fmt(self, f)
     }
 }
 
-impl Display for CidV0 {
+impl Display for Cid {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        f.write_str(&self.as_base58())
+        match self.version {
+            Version::V0 => f.write_str(&self.as_base58()),
+            Version::V1 => f.write_str(&self.as_base32()),
+        }
     }
 }
 
-impl<'de> Deserialize<'de> for CidV0 {
+impl<'de> Deserialize<'de> for Cid {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        struct CidV0Visitor;
-        impl Visitor<'_> for CidV0Visitor {
-            type Value = CidV0;
+        struct CidVisitor;
+        impl Visitor<'_> for CidVisitor {
+            type Value = Cid;
 
             fn expecting(&self, f: &mut Formatter) -> fmt::Result {
-                f.write_str("base58 encoded CID v0 ('Qm...')")
+                f.write_str("a base58 CID v0 ('Qm...') or base32 CID v1 ('b...')")
             }
 
             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                CidV0::from_base58(v).map_err(E::custom)
+                Cid::parse(v).map_err(E::custom)
             }
         }
 
-        deserializer.deserialize_str(CidV0Visitor)
+        deserializer.deserialize_str(CidVisitor)
     }
 }
 
-impl Serialize for CidV0 {
+impl Serialize for Cid {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.serialize_str(&self.as_base58())
+        serializer.serialize_str(&self.to_string())
     }
 }
 
+/// The alphabet used by the multibase `b` (RFC4648 base32, lowercase, no
+/// padding) encoding.
+const BASE32_ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+fn encode_base32(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity((bytes.len() * 8 + 4) / 5);
+    let mut buffer = 0u32;
+    let mut bits = 0u32;
+
+    for &byte in bytes {
+        buffer = (buffer << 8) | u32::from(byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            out.push(BASE32_ALPHABET[((buffer >> bits) & 0x1f) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        out.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 0x1f) as usize] as char);
+    }
+
+    out
+}
+
+fn decode_base32(s: &str) -> Result<Vec<u8>> {
+    let mut out = Vec::with_capacity(s.len() * 5 / 8);
+    let mut buffer = 0u32;
+    let mut bits = 0u32;
+
+    for c in s.chars() {
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|&b| b as char == c.to_ascii_lowercase())
+            .with_context(|| format!("invalid base32 character '{}'", c))?;
+        buffer = (buffer << 5) | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out.push(((buffer >> bits) & 0xff) as u8);
+        }
+    }
+
+    Ok(out)
+}
+
+/// Reads an unsigned LEB128 varint from the front of `bytes`, advancing past
+/// it.
+fn read_varint(bytes: &mut &[u8]) -> Result<u64> {
+    let mut value = 0u64;
+    let mut shift = 0;
+
+    loop {
+        let (&byte, rest) = bytes.split_first().context("truncated varint")?;
+        *bytes = rest;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+        ensure!(shift < 64, "varint is too large");
+    }
+}
+
+/// Writes `value` to `out` as an unsigned LEB128 varint.
+fn write_varint(mut value: u64, out: &mut Vec<u8>) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// The largest file size that fits in a single UnixFS block, and so is
+/// eligible for the local CID recomputation in [`Client::add_and_pin`] (and,
+/// via [`unixfs_block_cid`], `linker`'s offline stand-in). Larger files are
+/// chunked across multiple blocks by the node, which this module doesn't
+/// replicate.
+pub(crate) const UNIXFS_BLOCK_LIMIT: usize = 256 * 1024;
+
+/// Computes the CIDv0 that an IPFS node would assign to `data`, provided it
+/// fits in a single UnixFS block. Returns `None` if `data` is too large, in
+/// which case the node's reported hash can't be locally verified.
+///
+/// This builds the same bytes the node would: a UnixFS `Data` protobuf
+/// message (`Type = File`, `Data = data`, `filesize = data.len()`) wrapped,
+/// unlinked, as the `Data` field of a dag-pb `PBNode`, then SHA-256 hashed.
+pub(crate) fn unixfs_block_cid(data: &[u8]) -> Option<Cid> {
+    if data.len() > UNIXFS_BLOCK_LIMIT {
+        return None;
+    }
+
+    let mut unixfs_data = vec![0x08, 0x02]; // field 1 (Type), varint: File
+    if !data.is_empty() {
+        unixfs_data.push(0x12); // field 2 (Data), length-delimited
+        write_varint(data.len() as u64, &mut unixfs_data);
+        unixfs_data.extend_from_slice(data);
+    }
+    unixfs_data.push(0x18); // field 3 (filesize), varint
+    write_varint(data.len() as u64, &mut unixfs_data);
+
+    // dag-pb's `Data` is field 1 and `Links` is field 2; this node has no
+    // links, so only `Data` (the UnixFS message above) is encoded.
+    let mut pbnode = vec![0x0a]; // field 1 (Data), length-delimited
+    write_varint(unixfs_data.len() as u64, &mut pbnode);
+    pbnode.extend_from_slice(&unixfs_data);
+
+    Some(Cid::v0(Sha256::digest(&pbnode).into()))
+}
+
 /// Simple IPFS client that can add and pin file blobs.
 pub struct Client {
     base_url: Url,
@@ -104,14 +326,30 @@ impl Client {
     }
 
     /// Adds and pins a file to IPFS returning its CID.
-    pub fn add_and_pin(&self, file: &Path, filename: &Path) -> Result<CidV0> {
+    ///
+    /// If `file` fits in a single UnixFS block, its CID is recomputed
+    /// locally and checked against the node's response, so a misbehaving or
+    /// malicious node can't silently substitute different content. Larger,
+    /// multi-block files are trusted as reported.
+    pub fn add_and_pin(&self, file: &Path, filename: &Path) -> Result<Cid> {
         let added = self.add(file, filename)?;
         let cid = added
             .into_iter()
             .find(|file| file.name == filename)
             .context("file missing from added list")?
             .hash;
-        let pinned = self.pin(cid)?;
+
+        if let Some(expected) = unixfs_block_cid(&fs::read(file)?) {
+            ensure!(
+                expected == cid,
+                "IPFS node reported CID {} for '{}', but it locally hashes to {}",
+                cid,
+                filename.display(),
+                expected,
+            );
+        }
+
+        let pinned = self.pin(&cid)?;
         pinned
             .into_iter()
             .find(|pin| *pin == cid)
@@ -120,6 +358,74 @@ impl Client {
         Ok(cid)
     }
 
+    /// Adds and pins an entire directory tree to IPFS as a single wrapped
+    /// directory, returning the CID of the directory itself.
+    pub fn add_and_pin_directory(&self, root: &Path) -> Result<Cid> {
+        let added = self.add_directory(root)?;
+        let cid = added
+            .into_iter()
+            .find(|file| file.name.as_os_str().is_empty())
+            .context("wrapping directory missing from added list")?
+            .hash;
+        let pinned = self.pin(&cid)?;
+        pinned
+            .into_iter()
+            .find(|pin| *pin == cid)
+            .context("directory missing from pinned list")?;
+
+        Ok(cid)
+    }
+
+    /// Adds an entire directory tree to IPFS as a single wrapped directory.
+    ///
+    /// Every file under `root` is uploaded in one multipart request, using
+    /// its path relative to `root` as the form part's filename so that the
+    /// node can reconstruct the directory structure, with
+    /// `wrap-with-directory` set so the whole tree is addressable as a
+    /// single root. The returned list includes an entry for every file plus
+    /// one for the wrapping directory itself, identified by an empty name.
+    pub fn add_directory(&self, root: &Path) -> Result<Vec<Add>> {
+        let mut buffer = Vec::new();
+
+        let mut handle = Easy::new();
+        handle.url(
+            {
+                let mut url = self.url("api/v0/add")?;
+                url.query_pairs_mut()
+                    .append_pair("recursive", "true")
+                    .append_pair("wrap-with-directory", "true");
+                url
+            }
+            .as_str(),
+        )?;
+        handle.httppost({
+            let mut form = Form::new();
+            for file in walk_files(root)? {
+                let relative = file
+                    .strip_prefix(root)
+                    .context("walked file outside of its own root directory")?;
+                form.part("file")
+                    .file(&file)
+                    .filename(relative)
+                    .content_type("application/octet-stream")
+                    .add()?;
+            }
+            form
+        })?;
+        {
+            let mut transfer = handle.transfer();
+            transfer.write_function(|chunk| {
+                buffer.extend_from_slice(chunk);
+                Ok(chunk.len())
+            })?;
+            transfer.perform()?;
+        }
+
+        Ok(serde_json::Deserializer::from_slice(&buffer)
+            .into_iter::<Add>()
+            .collect::<Result<_, _>>()?)
+    }
+
     /// Adds a new file to IPFS.
     pub fn add(&self, file: &Path, filename: &Path) -> Result<Vec<Add>> {
         let mut buffer = Vec::new();
@@ -150,14 +456,14 @@ impl Client {
     }
 
     /// Pins a file to IPFS
-    pub fn pin(&self, cid: CidV0) -> Result<Vec<CidV0>> {
+    pub fn pin(&self, cid: &Cid) -> Result<Vec<Cid>> {
         let mut buffer = Vec::new();
 
         let mut handle = Easy::new();
         handle.url(
             {
                 let mut url = self.url("api/v0/pin/add")?;
-                url.query_pairs_mut().append_pair("arg", &cid.as_base58());
+                url.query_pairs_mut().append_pair("arg", &cid.to_string());
                 url
             }
             .as_str(),
@@ -176,18 +482,39 @@ impl Client {
     }
 }
 
+/// Recursively collects the paths to every regular file under `root`, for
+/// uploading a whole directory tree in one request.
+fn walk_files(root: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_owned()];
+
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if entry.file_type()?.is_dir() {
+                dirs.push(path);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+
+    Ok(files)
+}
+
 #[derive(Deserialize)]
 pub struct Add {
     #[serde(rename = "Name")]
     pub name: PathBuf,
     #[serde(rename = "Hash")]
-    pub hash: CidV0,
+    pub hash: Cid,
 }
 
 #[derive(Deserialize)]
 struct Pins {
     #[serde(rename = "Pins")]
-    pins: Vec<CidV0>,
+    pins: Vec<Cid>,
 }
 
 #[cfg(test)]
@@ -198,18 +525,18 @@ mod tests {
     #[test]
     fn cid_from_base58() {
         assert_eq!(
-            &CidV0::from_base58("QmY7Yh4UquoXHLPFo2XbhXkhBvFoPwmQUSa92pxnxjQuPU")
-                .unwrap()
-                .0,
-            b"\x91\x39\x83\x9e\x65\xfa\xbe\xa9\xef\xd2\x30\x89\x8a\xd8\xb5\x74\
-              \x50\x91\x47\xe4\x8d\x7c\x1e\x87\xa3\x3d\x6d\xa7\x0f\xd2\xef\xbf",
+            Cid::from_base58("QmY7Yh4UquoXHLPFo2XbhXkhBvFoPwmQUSa92pxnxjQuPU").unwrap(),
+            Cid::v0(
+                *b"\x91\x39\x83\x9e\x65\xfa\xbe\xa9\xef\xd2\x30\x89\x8a\xd8\xb5\x74\
+                   \x50\x91\x47\xe4\x8d\x7c\x1e\x87\xa3\x3d\x6d\xa7\x0f\xd2\xef\xbf"
+            ),
         );
     }
 
     #[test]
     fn cid_as_base58() {
         assert_eq!(
-            CidV0(
+            Cid::v0(
                 *b"\x91\x39\x83\x9e\x65\xfa\xbe\xa9\xef\xd2\x30\x89\x8a\xd8\xb5\x74\
                    \x50\x91\x47\xe4\x8d\x7c\x1e\x87\xa3\x3d\x6d\xa7\x0f\xd2\xef\xbf"
             )
@@ -221,8 +548,46 @@ mod tests {
     #[test]
     fn cid_serialization() {
         let base58 = json!("QmNLei78zWmzUdbeRB3CiUfAizWUrbeeZh5K1rhAQKCh51");
-        assert_eq!(serde_json::to_value(CidV0([0; 32])).unwrap(), base58);
-        assert_eq!(serde_json::from_value::<CidV0>(base58).unwrap().0, [0; 32]);
+        assert_eq!(serde_json::to_value(Cid::v0([0; 32])).unwrap(), base58);
+        assert_eq!(
+            serde_json::from_value::<Cid>(base58).unwrap(),
+            Cid::v0([0; 32]),
+        );
+    }
+
+    #[test]
+    fn cid_equality_ignores_version() {
+        let digest = [0x42; 32];
+        let v0 = Cid::v0(digest);
+        let v1 = Cid {
+            version: Version::V1,
+            codec: DAG_PB,
+            hash_code: SHA2_256,
+            digest: digest.to_vec(),
+        };
+
+        assert_eq!(v0, v1);
+    }
+
+    #[test]
+    fn cid_parse_v1_base32() {
+        let cid =
+            Cid::parse("bafkreifzjut3te2nhyekklss27nh3k72ysco7y32koao5eei66wof3k3im").unwrap();
+        assert_eq!(cid.to_string(), "bafkreifzjut3te2nhyekklss27nh3k72ysco7y32koao5eei66wof3k3im");
+    }
+
+    #[test]
+    fn unixfs_block_cid_of_empty_file() {
+        assert_eq!(
+            unixfs_block_cid(b"").unwrap().as_base58(),
+            "QmbFMke1KXqnYyBBWxB74N4c5SBnJMVAiMNRcGu6x1AwQH",
+        );
+    }
+
+    #[test]
+    fn unixfs_block_cid_none_above_block_limit() {
+        let data = vec![0u8; UNIXFS_BLOCK_LIMIT + 1];
+        assert!(unixfs_block_cid(&data).is_none());
     }
 
     #[test]
@@ -8,6 +8,8 @@ use std::{
     fmt::{self, Display, Formatter},
     io::Read,
     sync::atomic::{AtomicU64, Ordering},
+    thread,
+    time::Duration,
 };
 use url::Url;
 
@@ -15,6 +17,7 @@ use url::Url;
 pub struct Client {
     id: AtomicU64,
     url: Url,
+    retry: RetryPolicy,
 }
 
 impl Client {
@@ -23,9 +26,16 @@ impl Client {
         Ok(Self {
             id: AtomicU64::new(0),
             url: Url::parse(url)?,
+            retry: RetryPolicy::default(),
         })
     }
 
+    /// Sets the retry policy used for every request sent by this client.
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
     /// Returns the client's URL.
     pub fn url(&self) -> &Url {
         &self.url
@@ -37,26 +47,106 @@ impl Client {
         P: Serialize,
         R: DeserializeOwned,
     {
-        let request = serde_json::to_string(&Request {
+        let request = self.build_request(method, params);
+        self.send_with_retry(&request)
+    }
+
+    /// Executes a batch of JSONRPC requests in a single HTTP round-trip,
+    /// returning the results in submission order.
+    ///
+    /// Each entry is a `(method, params)` pair. Results are correlated with
+    /// requests using the `id` counter, so out-of-order responses from the
+    /// server are re-sorted before being returned.
+    pub fn execute_batch<P, R>(&self, calls: impl IntoIterator<Item = (&str, P)>) -> Result<Vec<R>>
+    where
+        P: Serialize,
+        R: DeserializeOwned,
+    {
+        let requests = calls
+            .into_iter()
+            .map(|(method, params)| self.build_request(method, params))
+            .collect::<Vec<_>>();
+        let order = requests.iter().map(|request| request.id).collect::<Vec<_>>();
+
+        let body = serde_json::to_string(&requests)?;
+        let raw = self.execute_raw_with_retry(body)?;
+
+        let mut responses = serde_json::from_str::<Vec<BatchEntry<R>>>(&raw)?
+            .into_iter()
+            .map(|entry| (entry.id(), entry))
+            .collect::<std::collections::HashMap<_, _>>();
+
+        order
+            .into_iter()
+            .map(|id| {
+                responses
+                    .remove(&id)
+                    .ok_or_else(|| anyhow::anyhow!("missing batch response for request id {}", id))?
+                    .into_result()
+            })
+            .collect()
+    }
+
+    fn build_request<P>(&self, method: &str, params: P) -> Request<'_, P> {
+        Request {
             jsonrpc: JsonRpcV2,
             method,
             params,
             // We don't really care about the ordering, just uniqueness.
             id: self.id.fetch_add(1, Ordering::Relaxed),
-        })?;
+        }
+    }
 
-        let response = self.execute_raw(request)?;
-        let response =
-            serde_json::from_str::<Response<R>>(&response).map_err(|err| -> anyhow::Error {
-                match serde_json::from_str::<ErrorResponse>(&response) {
-                    Ok(response) => response.error.into(),
-                    Err(_) => err.into(),
-                }
-            })?;
+    fn send_with_retry<P, R>(&self, request: &Request<'_, P>) -> Result<R>
+    where
+        P: Serialize,
+        R: DeserializeOwned,
+    {
+        let body = serde_json::to_string(request)?;
+        let response = self.execute_raw_with_retry(body)?;
+        let response = serde_json::from_str::<Response<R>>(&response)?;
 
         Ok(response.result)
     }
 
+    /// Sends `request`, retrying transient failures according to
+    /// `self.retry`.
+    ///
+    /// A JSONRPC-level error response (an HTTP 200 whose body is
+    /// `{"error": {...}}`) is decoded and classified alongside
+    /// transport-level errors here, inside the loop, so that
+    /// `RetryPolicy::retryable_codes` (e.g. rate-limit code `-32005`) is
+    /// actually retried instead of only being inspected after the last
+    /// attempt has already been made.
+    fn execute_raw_with_retry(&self, request: String) -> Result<String> {
+        let mut backoff = self.retry.backoff;
+        let mut last_err = None;
+        for attempt in 0..self.retry.max_attempts.max(1) {
+            match self.execute_raw(request.clone()).and_then(Self::check_error) {
+                Ok(response) => return Ok(response),
+                Err(err) if attempt + 1 < self.retry.max_attempts && self.retry.is_retryable(&err) => {
+                    last_err = Some(err);
+                    thread::sleep(backoff);
+                    backoff *= 2;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        Err(last_err.expect("max_attempts is always at least 1"))
+    }
+
+    /// Returns `response` unchanged, unless it decodes as a top-level
+    /// JSONRPC error response, in which case the error is decoded and
+    /// returned as an `Err` instead, so it can be classified by
+    /// [`RetryPolicy::is_retryable`] like any other error.
+    fn check_error(response: String) -> Result<String> {
+        match serde_json::from_str::<ErrorResponse>(&response) {
+            Ok(error_response) => Err(error_response.error.into()),
+            Err(_) => Ok(response),
+        }
+    }
+
     fn execute_raw(&self, request: String) -> Result<String> {
         let mut body = request.as_bytes();
         let mut buffer = Vec::new();
@@ -88,6 +178,57 @@ impl Client {
     }
 }
 
+/// Controls how many times, and under what conditions, a request is retried
+/// after a failure.
+///
+/// Graph-node and Ethereum endpoints routinely return transient rate-limit
+/// errors (for example JSONRPC error code `-32005`); a single-shot client
+/// surfaces these as hard failures even though retrying after a short delay
+/// usually succeeds.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts made for a single request, including
+    /// the first one. A value of `1` disables retrying.
+    pub max_attempts: u32,
+    /// The delay before the first retry. Doubles after every failed attempt
+    /// (exponential backoff).
+    pub backoff: Duration,
+    /// The JSONRPC error codes that are considered transient and therefore
+    /// retryable. Errors that don't carry one of these codes (including
+    /// transport-level errors) are always retried, since they are assumed to
+    /// be connectivity issues.
+    pub retryable_codes: Vec<i64>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            backoff: Duration::from_millis(200),
+            retryable_codes: vec![-32005],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    fn is_retryable(&self, err: &anyhow::Error) -> bool {
+        match err.downcast_ref::<Error>() {
+            Some(error) => self.retryable_codes.contains(&error.code),
+            // Not a JSONRPC error response, so it's a transport-level error
+            // (timeout, connection reset, ...), which is always retryable.
+            None => true,
+        }
+    }
+}
+
 struct JsonRpcV2;
 
 impl Serialize for JsonRpcV2 {
@@ -100,7 +241,7 @@ impl Serialize for JsonRpcV2 {
 }
 
 mod id {
-    use serde::ser::Serializer;
+    use serde::{de::Error as _, Deserializer, Serializer};
 
     pub fn serialize<S>(id: &u64, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -108,6 +249,14 @@ mod id {
     {
         serializer.serialize_str(&id.to_string())
     }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let id = String::deserialize(deserializer)?;
+        id.parse().map_err(D::Error::custom)
+    }
 }
 
 #[derive(Serialize)]
@@ -129,6 +278,35 @@ struct ErrorResponse {
     error: Error,
 }
 
+/// A single entry in a batch JSONRPC response.
+#[derive(Deserialize)]
+struct BatchEntry<R> {
+    #[serde(with = "id")]
+    id: u64,
+    #[serde(flatten)]
+    payload: BatchPayload<R>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BatchPayload<R> {
+    Result { result: R },
+    Error { error: Error },
+}
+
+impl<R> BatchEntry<R> {
+    fn id(&self) -> u64 {
+        self.id
+    }
+
+    fn into_result(self) -> Result<R> {
+        match self.payload {
+            BatchPayload::Result { result } => Ok(result),
+            BatchPayload::Error { error } => Err(error.into()),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct Error {
     code: i64,
@@ -205,6 +383,72 @@ mod tests {
         assert_eq!(error.message, "error");
     }
 
+    #[test]
+    fn deserialize_batch_out_of_order() {
+        let batch = serde_json::from_value::<Vec<BatchEntry<i32>>>(json!([
+            { "jsonrpc": "2.0", "result": 2, "id": "1" },
+            { "jsonrpc": "2.0", "result": 0, "id": "0" },
+        ]))
+        .unwrap();
+
+        let mut by_id = batch
+            .into_iter()
+            .map(|entry| (entry.id(), entry))
+            .collect::<std::collections::HashMap<_, _>>();
+        assert_eq!(by_id.remove(&0).unwrap().into_result().unwrap(), 0);
+        assert_eq!(by_id.remove(&1).unwrap().into_result().unwrap(), 2);
+    }
+
+    #[test]
+    fn deserialize_batch_entry_error() {
+        let entry = serde_json::from_value::<BatchEntry<i32>>(json!({
+            "jsonrpc": "2.0",
+            "error": { "code": -32005, "message": "rate limited", "data": "" },
+            "id": "0",
+        }))
+        .unwrap();
+
+        assert!(entry.into_result().is_err());
+    }
+
+    #[test]
+    fn check_error_decodes_jsonrpc_error_response() {
+        let response = json!({
+            "jsonrpc": "2.0",
+            "error": { "code": -32005, "message": "rate limited", "data": "" },
+            "id": "1",
+        })
+        .to_string();
+
+        let err = Client::check_error(response).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>().unwrap().code, -32005);
+    }
+
+    #[test]
+    fn check_error_passes_through_non_error_responses() {
+        let response = json!({ "jsonrpc": "2.0", "result": 19, "id": "1" }).to_string();
+        assert_eq!(Client::check_error(response.clone()).unwrap(), response);
+    }
+
+    #[test]
+    fn retry_policy_classifies_errors() {
+        let retry = RetryPolicy::default();
+        assert!(retry.is_retryable(
+            &Error {
+                code: -32005,
+                message: "rate limited".to_owned(),
+            }
+            .into()
+        ));
+        assert!(!retry.is_retryable(
+            &Error {
+                code: -32602,
+                message: "invalid params".to_owned(),
+            }
+            .into()
+        ));
+    }
+
     #[test]
     #[ignore]
     fn eth_rpc() {
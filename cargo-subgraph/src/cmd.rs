@@ -3,8 +3,11 @@
 use anyhow::Result;
 use structopt::StructOpt;
 
+pub mod abigen;
+pub mod build;
 pub mod create;
 pub mod deploy;
+pub mod graph;
 
 #[derive(StructOpt)]
 #[structopt(name = "cargo-subgraph", about = "Manage subgraphs written in Rust 🦀")]
@@ -13,11 +16,20 @@ pub enum Options {
     Create(create::Options),
     #[structopt(about = "Build and deploy a subgraph.")]
     Deploy(deploy::Options),
+    #[structopt(about = "Build a Rust subgraph.")]
+    Build(build::Options),
+    #[structopt(about = "Generate typed Rust bindings from contract ABIs.")]
+    Abigen(abigen::Options),
+    #[structopt(about = "Print a Graphviz DOT diagram of a subgraph's topology.")]
+    Graph(graph::Options),
 }
 
 pub fn run() -> Result<()> {
     match Options::from_args() {
         Options::Create(options) => create::run(options),
         Options::Deploy(options) => deploy::run(options),
+        Options::Build(options) => build::run(options),
+        Options::Abigen(options) => abigen::run(options),
+        Options::Graph(options) => graph::run(options),
     }
 }
@@ -0,0 +1,36 @@
+//! Subcommand used for generating typed Rust bindings from contract ABIs.
+
+use crate::{abigen::Abi, manifest::Manifest};
+use anyhow::{Context as _, Result};
+use std::{fs, path::PathBuf};
+use structopt::StructOpt;
+
+#[derive(StructOpt)]
+pub struct Options {
+    #[structopt(long, help = "Path to subgraph.yaml", default_value = "subgraph.yaml")]
+    manifest: PathBuf,
+
+    #[structopt(
+        long,
+        help = "Directory to write generated ABI bindings to.",
+        default_value = "src/abi"
+    )]
+    output_dir: PathBuf,
+}
+
+pub fn run(options: Options) -> Result<()> {
+    let manifest = Manifest::read(&options.manifest)?;
+
+    fs::create_dir_all(&options.output_dir)
+        .with_context(|| format!("error creating '{}'", options.output_dir.display()))?;
+    for (name, abi_path) in manifest.abis() {
+        let abi = Abi::read(&abi_path)?;
+        let source = abi.generate(&name);
+
+        let output = options.output_dir.join(format!("{}.rs", name));
+        fs::write(&output, source)
+            .with_context(|| format!("error writing '{}'", output.display()))?;
+    }
+
+    Ok(())
+}
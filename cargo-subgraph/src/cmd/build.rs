@@ -0,0 +1,76 @@
+//! Subgraph build subcommand implementation.
+
+use crate::{
+    api::cargo,
+    linker::{Codec, Linker},
+    manifest::Manifest,
+    mappings::{MappingOpions, Mappings},
+};
+use anyhow::{Context as _, Result};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(StructOpt)]
+#[structopt(about = "Build a Rust subgraph.")]
+pub struct Options {
+    #[structopt(long, help = "Path to subgraph.yaml")]
+    subgraph_manifest_path: Option<PathBuf>,
+
+    /// Output directory for build files. If this option is not specified
+    /// then the build output is written to the crate's `target` directory.
+    #[structopt(short, long)]
+    output_dir: Option<PathBuf>,
+
+    #[structopt(long, help = "Optimize compiled mappings with `wasm-opt`.")]
+    wasm_opt: bool,
+
+    /// Compress linked resources with the given codec ('gzip' or 'bzip2')
+    /// before writing them out. Vendored `.wasm` files are left uncompressed
+    /// since they're often already size-optimized by `wasm-opt`.
+    #[structopt(long)]
+    compress: Option<Codec>,
+}
+
+/// Run the `build` subcommand.
+///
+/// This performs the same pipeline as `deploy`, up to (but not including)
+/// deployment: the manifest is read, mappings are compiled and linked with an
+/// offline `Linker`, and the resolved manifest plus every linked
+/// `DiskResource` is written to `output_dir` under content-addressed names.
+/// Since no IPFS node is contacted, this gives CI a deterministic,
+/// network-free build artifact and a dry-run of linking.
+pub fn run(options: Options) -> Result<()> {
+    let manifest = Manifest::read(
+        &options
+            .subgraph_manifest_path
+            .map(Result::<_>::Ok)
+            .unwrap_or_else(|| {
+                Ok(cargo::root()?
+                    .parent()
+                    .context("Cargo manifest has no parent directory")?
+                    .join("subgraph.yaml"))
+            })?,
+    )?;
+
+    let outdir = match options.output_dir {
+        Some(outdir) => outdir,
+        None => cargo::target_directory()?
+            .join("subgraph")
+            .join(cargo::crate_name()?),
+    };
+
+    let root_cid = manifest.link(
+        Linker::offline(outdir.clone(), options.compress)?,
+        Mappings::compile(MappingOpions {
+            optimize: options.wasm_opt,
+        })?,
+    )?;
+
+    println!("Build output written to {}", outdir.display());
+    println!("Root hash (would deploy as): /ipfs/{}", root_cid);
+    if let Some(codec) = options.compress {
+        println!("Compression: {}", codec.label());
+    }
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 //! Subcommand used for creating a new subgraph.
 
-use crate::api::graph;
+use crate::api::graph::{self, SyncClient as _};
 use anyhow::{Context as _, Result};
 use structopt::StructOpt;
 use url::Url;
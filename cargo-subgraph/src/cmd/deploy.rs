@@ -1,8 +1,11 @@
 //! Subcommand used for creating a new subgraph.
 
 use crate::{
-    api::{cargo, graph},
-    linker::Linker,
+    api::{
+        cargo,
+        graph::{self, AsyncClient as _, Confirm, SyncClient as _},
+    },
+    linker::{Codec, Linker},
     manifest::Manifest,
     mappings::{MappingOpions, Mappings},
 };
@@ -19,18 +22,46 @@ pub struct Options {
     #[structopt(long, help = "Path to subgraph.yaml")]
     subgraph_manifest_path: Option<PathBuf>,
 
-    #[structopt(long, help = "URL of the Graph node to deploy to.")]
-    graph_node: Url,
+    #[structopt(
+        long,
+        help = "URL of the Graph node to deploy to. Required unless --output-dir is set."
+    )]
+    graph_node: Option<Url>,
 
-    #[structopt(long, help = "URL of the IPFS node to upload to.")]
-    ipfs_node: Url,
+    #[structopt(
+        long,
+        help = "URL of the IPFS node to upload to. Required unless --output-dir is set."
+    )]
+    ipfs_node: Option<Url>,
+
+    /// Output directory for build files. If this option is set, the linked
+    /// manifest and artifacts are written here instead of being uploaded to
+    /// IPFS, and the subgraph is not deployed.
+    #[structopt(short, long)]
+    output_dir: Option<PathBuf>,
 
     #[structopt(long, help = "Optimize compiled mappings with `wasm-opt`.")]
     wasm_opt: bool,
+
+    /// Compress linked resources with the given codec ('gzip' or 'bzip2')
+    /// before uploading them. Vendored `.wasm` files are left uncompressed
+    /// since they're often already size-optimized by `wasm-opt`.
+    #[structopt(long)]
+    compress: Option<Codec>,
+
+    /// Block until the Graph node acknowledges the deployment, retrying on
+    /// transient failures, instead of returning as soon as the request is
+    /// sent. Implied by `--wait-for-block`.
+    #[structopt(long)]
+    confirm: bool,
+
+    /// Block until the deployment's indexing has caught up to the given
+    /// block number, in addition to what `--confirm` waits for.
+    #[structopt(long)]
+    wait_for_block: Option<u64>,
 }
 
 pub fn run(options: Options) -> Result<()> {
-    let client = graph::Client::new(options.graph_node);
     let manifest = Manifest::read(
         &options
             .subgraph_manifest_path
@@ -42,15 +73,51 @@ pub fn run(options: Options) -> Result<()> {
                     .join("subgraph.yaml"))
             })?,
     )?;
-    client.deploy(
-        &options.subgraph_name,
-        manifest.link(
-            Linker::new(options.ipfs_node)?,
-            Mappings::compile(MappingOpions {
-                optimize: options.wasm_opt,
-            })?,
-        )?,
-    )?;
+    let mappings = Mappings::compile(MappingOpions {
+        optimize: options.wasm_opt,
+    })?;
+
+    if let Some(outdir) = options.output_dir {
+        let root_cid = manifest.link(Linker::offline(outdir.clone(), options.compress)?, mappings)?;
+
+        println!("Build output written to {}", outdir.display());
+        println!("Root hash (would deploy as): /ipfs/{}", root_cid);
+        if let Some(codec) = options.compress {
+            println!("Compression: {}", codec.label());
+        }
+
+        return Ok(());
+    }
+
+    let ipfs_node = options
+        .ipfs_node
+        .context("--ipfs-node is required unless --output-dir is set")?;
+    let graph_node = options
+        .graph_node
+        .context("--graph-node is required unless --output-dir is set")?;
+
+    let client = graph::Client::new(graph_node);
+    let linker = Linker::new(ipfs_node, options.compress)?;
+    let codec = linker.codec();
+    let cid = manifest.link(linker, mappings)?;
+    let routes = if options.confirm || options.wait_for_block.is_some() {
+        client.deploy_and_confirm(
+            &options.subgraph_name,
+            &cid,
+            Confirm {
+                target_block: options.wait_for_block,
+                ..Confirm::default()
+            },
+        )?
+    } else {
+        client.deploy(&options.subgraph_name, &cid)?
+    };
+
+    println!("Deployed {} (/ipfs/{})", options.subgraph_name, cid);
+    println!("Playground: {}", routes.playground);
+    if let Some(codec) = codec {
+        println!("Compression: {}", codec.label());
+    }
 
     Ok(())
 }
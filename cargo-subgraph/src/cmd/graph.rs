@@ -0,0 +1,83 @@
+//! Subcommand used for visualizing a subgraph's topology as a Graphviz DOT
+//! diagram.
+
+use crate::manifest::Manifest;
+use anyhow::Result;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(StructOpt)]
+pub struct Options {
+    #[structopt(long, help = "Path to subgraph.yaml", default_value = "subgraph.yaml")]
+    manifest: PathBuf,
+
+    #[structopt(long, help = "Emit an undirected graph instead of a directed one.")]
+    undirected: bool,
+}
+
+pub fn run(options: Options) -> Result<()> {
+    let manifest = Manifest::read(&options.manifest)?;
+    print!("{}", dot(&manifest, !options.undirected));
+
+    Ok(())
+}
+
+/// Renders the data sources, handlers and ABIs of a manifest as a Graphviz
+/// DOT graph.
+///
+/// When `directed` is `true`, a `digraph` with `->` edges is emitted,
+/// modeling data-flow from data sources through their handlers into the
+/// entity types they write. Otherwise an undirected `graph` with `--` edges
+/// is emitted.
+fn dot(manifest: &Manifest, directed: bool) -> String {
+    let (kind, edge) = if directed {
+        ("digraph", "->")
+    } else {
+        ("graph", "--")
+    };
+
+    let mut out = format!("{} subgraph {{\n", kind);
+    out.push_str("    rankdir=LR;\n");
+
+    for data_source in manifest.data_sources() {
+        let ds_node = quote(data_source.name);
+        out.push_str(&format!(
+            "    {} [shape=box, label=\"{}\\n({})\"];\n",
+            ds_node, data_source.name, data_source.kind,
+        ));
+
+        for abi in &data_source.abis {
+            let abi_node = quote(&format!("abi:{}", abi));
+            out.push_str(&format!(
+                "    {} [shape=note, label=\"ABI: {}\"];\n",
+                abi_node, abi,
+            ));
+            out.push_str(&format!("    {} {} {};\n", abi_node, edge, ds_node));
+        }
+
+        for (name, kind) in &data_source.handlers {
+            let handler_node = quote(&format!("{}:{}", data_source.name, name));
+            out.push_str(&format!(
+                "    {} [shape=ellipse, label=\"{}\\n({})\"];\n",
+                handler_node, name, kind,
+            ));
+            out.push_str(&format!("    {} {} {};\n", ds_node, edge, handler_node));
+
+            for entity in &data_source.entities {
+                let entity_node = quote(entity);
+                out.push_str(&format!(
+                    "    {} [shape=cylinder, label=\"{}\"];\n",
+                    entity_node, entity,
+                ));
+                out.push_str(&format!("    {} {} {};\n", handler_node, edge, entity_node));
+            }
+        }
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "\\\""))
+}
@@ -3,47 +3,102 @@
 
 use crate::api::{
     cargo,
-    ipfs::{CidV0, Client},
+    ipfs::{unixfs_block_cid, Cid, Client},
 };
-use anyhow::{ensure, Context as _, Result};
+use anyhow::{bail, ensure, Context as _, Result};
+use bzip2::write::BzEncoder;
+use flate2::{write::GzEncoder, Compression};
+use sha2::{Digest, Sha256};
 use std::{
     fs,
+    io::{self, Write},
     path::{Component, Path, PathBuf},
+    str::FromStr,
 };
 use url::Url;
 
-/// A linker for writing and uploading resources to IPFS for subgraph use.
+/// A linker for writing resources to a local output directory, optionally
+/// uploading them to IPFS for subgraph use.
 pub struct Linker {
-    ipfs: Client,
+    destination: Destination,
     outdir: PathBuf,
+    /// Codec used to compress resources before writing/uploading them,
+    /// unless a resource opts out (see [`Resource::without_compression`]).
+    compress: Option<Codec>,
+}
+
+enum Destination {
+    Ipfs(Client),
+    /// Write resources to `outdir` only. Used for offline builds, where a
+    /// locally computed hash stands in for the real IPFS CID.
+    Offline,
 }
 
 impl Linker {
     /// Creates a new resource linker from the specified IPFS base URL.
-    pub fn new(ipfs_url: Url) -> Result<Self> {
-        let ipfs = Client::new(ipfs_url);
+    pub fn new(ipfs_url: Url, compress: Option<Codec>) -> Result<Self> {
         let outdir = cargo::target_directory()?
             .join("subgraph")
             .join(cargo::crate_name()?);
         fs::create_dir_all(&outdir)?;
 
-        Ok(Self { ipfs, outdir })
+        Ok(Self {
+            destination: Destination::Ipfs(Client::new(ipfs_url)),
+            outdir,
+            compress,
+        })
+    }
+
+    /// Creates a resource linker that only writes resources under `outdir`,
+    /// without any network access. This is what powers `cargo subgraph
+    /// build`'s network-free, deterministic build artifacts.
+    pub fn offline(outdir: PathBuf, compress: Option<Codec>) -> Result<Self> {
+        fs::create_dir_all(&outdir)?;
+
+        Ok(Self {
+            destination: Destination::Offline,
+            outdir,
+            compress,
+        })
     }
 
     #[cfg(test)]
     pub fn test() -> (tempfile::TempDir, Self) {
         let outdir = tempfile::tempdir().unwrap();
         let linker = Linker {
-            ipfs: Client::new(Url::parse("http://localhost:5001").unwrap()),
+            destination: Destination::Ipfs(Client::new(Url::parse("http://localhost:5001").unwrap())),
             outdir: outdir.path().to_owned(),
+            compress: None,
         };
 
         (outdir, linker)
     }
 
-    /// Links a resource, writing it to the output directory and uploading it to
-    /// IPFS.
-    pub fn link<S>(&self, resource: Resource<S>) -> Result<CidV0>
+    /// Returns the compression codec this linker applies to resources, if
+    /// any, so callers (e.g. the `deploy` subcommand) can report or forward
+    /// it alongside the upload.
+    pub fn codec(&self) -> Option<Codec> {
+        self.compress
+    }
+
+    /// Bundles every resource linked so far into a single content-addressed
+    /// directory, returning its root CID.
+    ///
+    /// Linking resources individually (see [`Linker::link`]) leaves a
+    /// deployment with as many unrelated CIDs as it has files. This packs the
+    /// entire output directory into one IPFS directory object instead, so
+    /// the manifest and everything it references share a single, stable root
+    /// hash to pin and reference.
+    pub fn finalize(&self) -> Result<Cid> {
+        match &self.destination {
+            Destination::Ipfs(ipfs) => ipfs.add_and_pin_directory(&self.outdir),
+            Destination::Offline => local_directory_hash(&self.outdir),
+        }
+    }
+
+    /// Links a resource, writing it to the output directory and, unless this
+    /// is an offline linker, uploading it to IPFS.
+    pub fn link<S>(&self, resource: Resource<S>) -> Result<Cid>
     where
         S: Source,
     {
@@ -53,14 +108,155 @@ impl Linker {
             "linking file ends up outside of output directory",
         );
         fs::create_dir_all(output.parent().context("output path has no parent")?)?;
-        resource.source.write_to_output(&output)?;
 
-        let hash = self.ipfs.add_and_pin(&output, Some(resource.name))?;
+        let codec = if resource.compress { self.compress } else { None };
+        resource.source.write_to_output(&output, codec)?;
+
+        let hash = match &self.destination {
+            Destination::Ipfs(ipfs) => ipfs.add_and_pin(&output, Some(resource.name))?,
+            Destination::Offline => local_hash(&output)?,
+        };
 
         Ok(hash)
     }
 }
 
+/// A compression codec that can be applied to resources before they are
+/// written to the output directory and uploaded to IPFS.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Codec {
+    Gzip,
+    Bzip2,
+}
+
+impl Codec {
+    /// Returns a short label identifying this codec, suitable for reporting
+    /// which encoding a deploy used.
+    pub fn label(self) -> &'static str {
+        match self {
+            Codec::Gzip => "gzip",
+            Codec::Bzip2 => "bzip2",
+        }
+    }
+}
+
+impl FromStr for Codec {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "gzip" => Ok(Codec::Gzip),
+            "bzip2" => Ok(Codec::Bzip2),
+            _ => bail!("unsupported compression codec '{}', expected 'gzip' or 'bzip2'", s),
+        }
+    }
+}
+
+/// A [`Write`] implementation that streams into an output file, optionally
+/// through a compressor, so that resources never need to be buffered
+/// uncompressed and compressed at the same time.
+enum CompressWriter {
+    Plain(fs::File),
+    Gzip(GzEncoder<fs::File>),
+    Bzip2(BzEncoder<fs::File>),
+}
+
+impl CompressWriter {
+    fn create(output: &Path, codec: Option<Codec>) -> Result<Self> {
+        let file = fs::File::create(output)?;
+        Ok(match codec {
+            None => CompressWriter::Plain(file),
+            Some(Codec::Gzip) => CompressWriter::Gzip(GzEncoder::new(file, Compression::default())),
+            Some(Codec::Bzip2) => {
+                CompressWriter::Bzip2(BzEncoder::new(file, bzip2::Compression::default()))
+            }
+        })
+    }
+
+    /// Flushes and writes out any trailing compressor frame data.
+    fn finish(self) -> Result<()> {
+        match self {
+            CompressWriter::Plain(_) => {}
+            CompressWriter::Gzip(w) => {
+                w.finish()?;
+            }
+            CompressWriter::Bzip2(w) => {
+                w.finish()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Write for CompressWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            CompressWriter::Plain(w) => w.write(buf),
+            CompressWriter::Gzip(w) => w.write(buf),
+            CompressWriter::Bzip2(w) => w.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            CompressWriter::Plain(w) => w.flush(),
+            CompressWriter::Gzip(w) => w.flush(),
+            CompressWriter::Bzip2(w) => w.flush(),
+        }
+    }
+}
+
+/// Computes a resource's IPFS CID without any network access.
+///
+/// Resources that fit in a single UnixFS block (the common case: schemas,
+/// ABIs, small mappings) get the same CID an IPFS node would actually
+/// assign, via [`unixfs_block_cid`]. Larger resources fall back to a plain
+/// SHA-256 of the file's contents, which does *not* match the real CID
+/// (multi-block UnixFS chunking isn't replicated here); it only needs to be
+/// stable across builds of the same inputs.
+fn local_hash(path: &Path) -> Result<Cid> {
+    let contents = fs::read(path)?;
+    if let Some(cid) = unixfs_block_cid(&contents) {
+        return Ok(cid);
+    }
+
+    let digest = Sha256::digest(&contents);
+    Ok(Cid::v0(digest.into()))
+}
+
+/// Computes a deterministic local stand-in for a whole directory's IPFS
+/// directory CID, without any network access.
+///
+/// This hashes each file's relative path together with its contents, sorted
+/// by path so the result doesn't depend on directory iteration order; like
+/// [`local_hash`], it does *not* match the CID IPFS would actually assign.
+fn local_directory_hash(root: &Path) -> Result<Cid> {
+    let mut relative_paths = Vec::new();
+    let mut dirs = vec![root.to_owned()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if entry.file_type()?.is_dir() {
+                dirs.push(path);
+            } else {
+                relative_paths.push(path.strip_prefix(root)?.to_owned());
+            }
+        }
+    }
+    relative_paths.sort();
+
+    let mut hasher = Sha256::new();
+    for relative_path in relative_paths {
+        hasher.update(relative_path.to_string_lossy().as_bytes());
+        hasher.update(b"\0");
+        hasher.update(fs::read(root.join(&relative_path))?);
+        hasher.update(b"\n");
+    }
+
+    Ok(Cid::v0(hasher.finalize().into()))
+}
+
 /// A file for linking.
 pub struct Resource<'a, S> {
     /// The path to the file on disk.
@@ -68,10 +264,23 @@ pub struct Resource<'a, S> {
     /// A descriptive name for the file that will be used to place the file in
     /// the output directory as well as the file name when uploading to IPFS.
     pub name: &'a Path,
+    /// Whether the linker's configured compression codec, if any, should be
+    /// applied to this resource. Defaults to `true`.
+    compress: bool,
+}
+
+impl<'a, S> Resource<'a, S> {
+    /// Opts this resource out of the linker's compression codec, e.g. for
+    /// vendored `.wasm` files that are often already size-optimized by
+    /// `wasm-opt`.
+    pub fn without_compression(mut self) -> Self {
+        self.compress = false;
+        self
+    }
 }
 
 pub trait Source {
-    fn write_to_output(&self, output: &Path) -> Result<()>;
+    fn write_to_output(&self, output: &Path, codec: Option<Codec>) -> Result<()>;
 }
 
 /// A resource from a file already on disk.
@@ -83,14 +292,17 @@ impl<'a> DiskResource<'a> {
         Resource {
             source: root.join(relative),
             name: relative,
+            compress: true,
         }
     }
 }
 
 impl Source for PathBuf {
-    fn write_to_output(&self, output: &Path) -> Result<()> {
-        fs::copy(self, output)?;
-        Ok(())
+    fn write_to_output(&self, output: &Path, codec: Option<Codec>) -> Result<()> {
+        let mut input = fs::File::open(self)?;
+        let mut writer = CompressWriter::create(output, codec)?;
+        io::copy(&mut input, &mut writer)?;
+        writer.finish()
     }
 }
 
@@ -103,14 +315,16 @@ impl<'a> BufferedResource<'a> {
         Resource {
             source: contents,
             name: relative,
+            compress: true,
         }
     }
 }
 
 impl Source for &'_ [u8] {
-    fn write_to_output(&self, output: &Path) -> Result<()> {
-        fs::write(output, self)?;
-        Ok(())
+    fn write_to_output(&self, output: &Path, codec: Option<Codec>) -> Result<()> {
+        let mut writer = CompressWriter::create(output, codec)?;
+        writer.write_all(self)?;
+        writer.finish()
     }
 }
 
@@ -1,3 +1,4 @@
+mod abigen;
 mod api;
 mod cmd;
 mod linker;
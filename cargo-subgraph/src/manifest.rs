@@ -8,7 +8,7 @@
 //! in the final manifest.
 
 use crate::{
-    api::ipfs::CidV0,
+    api::ipfs::Cid,
     linker::{Linker, Resource, Source},
     mappings::Mappings,
 };
@@ -49,9 +49,15 @@ impl Manifest {
         })
     }
 
-    /// Links a manifest, replacing all paths with IPFS locations and returning
-    /// the IPFS CID v0 hash of the uploaded manifest.
-    pub fn link(self, linker: Linker, mappings: Mappings) -> Result<CidV0> {
+    /// Links a manifest, replacing all paths with IPFS locations, and
+    /// returns the IPFS CID v0 hash of the bundled output directory
+    /// (manifest, schema, ABIs, and mappings) as a single root.
+    ///
+    /// `templates` (dynamic data sources instantiated at runtime) are linked
+    /// the same way as `dataSources`. Everything else, including a `graft`
+    /// section, is left untouched, same as any other key this module doesn't
+    /// model.
+    pub fn link(self, linker: Linker, mappings: Mappings) -> Result<Cid> {
         let Self {
             root,
             mut document,
@@ -63,34 +69,94 @@ impl Manifest {
         // into `Files` so we know its valid.
 
         document["schema"]["file"] = linker.file(&data.schema.file)?;
-        for (i, data_source) in data.data_sources.iter().enumerate() {
-            let d_data_source = &mut document["dataSources"][i];
-
-            let mapping_file = &data_source.mapping.file;
-            d_data_source["mapping"]["file"] = if mapping_file.extension() == Some("wasm".as_ref())
-            {
-                // The subgraph is asking for a vendored Wasm file. Nothing more
-                // to do!
-                linker.file(mapping_file)?
-            } else {
-                linker.link(mappings.resolve(mapping_file, &data_source.mapping.api_version)?)?
-            };
-
-            for (i, abi) in data_source.mapping.abis.iter().enumerate() {
-                let d_abi = &mut d_data_source["mapping"]["abis"][i];
-                d_abi["file"] = linker.file(&abi.file)?;
-            }
-        }
+        link_data_sources(&mut document, "dataSources", &data.data_sources, &linker, &mappings)?;
+        link_data_sources(&mut document, "templates", &data.templates, &linker, &mappings)?;
+
+        linker.finish(&document)?;
+        linker.finalize()
+    }
+
+    /// Returns the absolute paths to every ABI file referenced by a data
+    /// source in the manifest, paired with the ABI's name.
+    ///
+    /// This is primarily used to drive ABI code generation, where each entry
+    /// corresponds to a Rust module that should be generated for a contract.
+    pub fn abis(&self) -> Vec<(String, PathBuf)> {
+        self.data
+            .data_sources
+            .iter()
+            .flat_map(|data_source| &data_source.mapping.abis)
+            .map(|abi| (abi.name.clone(), self.root.join(&abi.file)))
+            .collect()
+    }
 
-        linker.finish(&document)
+    /// Returns a topology view of every data source in the manifest, for
+    /// visualizing how handlers map to ABIs and entities.
+    pub fn data_sources(&self) -> Vec<DataSourceTopology<'_>> {
+        self.data
+            .data_sources
+            .iter()
+            .map(|data_source| DataSourceTopology {
+                name: &data_source.name,
+                kind: &data_source.kind,
+                abis: data_source
+                    .mapping
+                    .abis
+                    .iter()
+                    .map(|abi| abi.name.as_str())
+                    .collect(),
+                entities: data_source
+                    .mapping
+                    .entities
+                    .iter()
+                    .map(String::as_str)
+                    .collect(),
+                handlers: data_source
+                    .mapping
+                    .event_handlers
+                    .iter()
+                    .map(|handler| (handler.event.as_str(), "event"))
+                    .chain(
+                        data_source
+                            .mapping
+                            .call_handlers
+                            .iter()
+                            .map(|handler| (handler.function.as_str(), "call")),
+                    )
+                    .chain(
+                        data_source
+                            .mapping
+                            .block_handlers
+                            .iter()
+                            .map(|handler| (handler.handler.as_str(), "block")),
+                    )
+                    .collect(),
+            })
+            .collect()
     }
 }
 
+/// A read-only view of a data source's topology: its ABIs, handlers, and the
+/// entity types its mapping writes.
+pub struct DataSourceTopology<'a> {
+    pub name: &'a str,
+    pub kind: &'a str,
+    pub abis: Vec<&'a str>,
+    pub entities: Vec<&'a str>,
+    /// Handler `(name, kind)` pairs, where `kind` is one of `"event"`,
+    /// `"call"` or `"block"`.
+    pub handlers: Vec<(&'a str, &'static str)>,
+}
+
 #[derive(Deserialize)]
 struct Data<F> {
     schema: Schema<F>,
     #[serde(rename = "dataSources")]
     data_sources: Vec<DataSource<F>>,
+    /// Dynamic data source templates, instantiated at runtime by mappings.
+    /// These reference mapping/ABI files exactly like `dataSources` do.
+    #[serde(default)]
+    templates: Vec<DataSource<F>>,
 }
 
 #[derive(Deserialize)]
@@ -100,6 +166,8 @@ struct Schema<F> {
 
 #[derive(Deserialize)]
 struct DataSource<F> {
+    name: String,
+    kind: String,
     mapping: Mapping<F>,
 }
 
@@ -109,14 +177,69 @@ struct Mapping<F> {
     api_version: String,
     abis: Vec<Abi<F>>,
     file: F,
+    #[serde(default)]
+    entities: Vec<String>,
+    #[serde(rename = "eventHandlers", default)]
+    event_handlers: Vec<EventHandler>,
+    #[serde(rename = "callHandlers", default)]
+    call_handlers: Vec<CallHandler>,
+    #[serde(rename = "blockHandlers", default)]
+    block_handlers: Vec<BlockHandler>,
 }
 
 #[derive(Deserialize)]
 struct Abi<F> {
+    name: String,
     file: F,
 }
 
-struct Link(CidV0);
+#[derive(Deserialize)]
+struct EventHandler {
+    event: String,
+}
+
+#[derive(Deserialize)]
+struct CallHandler {
+    function: String,
+}
+
+#[derive(Deserialize)]
+struct BlockHandler {
+    handler: String,
+}
+
+/// Resolves and replaces the `mapping.file` and `mapping.abis[].file` entries
+/// of every entry in a `dataSources`- or `templates`-shaped array, leaving
+/// `document[key]` untouched if `data_sources` is empty.
+fn link_data_sources(
+    document: &mut Value,
+    key: &str,
+    data_sources: &[DataSource<PathBuf>],
+    linker: &LinkAdapter,
+    mappings: &Mappings,
+) -> Result<()> {
+    for (i, data_source) in data_sources.iter().enumerate() {
+        let d_data_source = &mut document[key][i];
+
+        let mapping_file = &data_source.mapping.file;
+        d_data_source["mapping"]["file"] = if mapping_file.extension() == Some("wasm".as_ref()) {
+            // The subgraph is asking for a vendored Wasm file. Nothing more
+            // to do!
+            linker.vendored_wasm_file(mapping_file)?
+        } else {
+            linker.link(mappings.resolve(mapping_file, &data_source.mapping.api_version)?)?
+        };
+
+        for (i, abi) in data_source.mapping.abis.iter().enumerate() {
+            let d_abi = &mut d_data_source["mapping"]["abis"][i];
+            d_abi["file"] = linker.file(&abi.file)?;
+        }
+    }
+
+    Ok(())
+}
+
+struct Link(Cid);
 
 impl Serialize for Link {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -146,13 +269,26 @@ impl LinkAdapter {
         self.link(Resource::file(&self.root, path))
     }
 
-    fn finish(&self, document: &Value) -> Result<CidV0> {
+    /// Links a vendored `.wasm` file without the linker's configured
+    /// compression codec, since these are typically already size-optimized
+    /// by `wasm-opt`.
+    fn vendored_wasm_file(&self, path: &Path) -> Result<Value> {
+        self.link(Resource::file(&self.root, path).without_compression())
+    }
+
+    fn finish(&self, document: &Value) -> Result<Cid> {
         let bytes = serde_yaml::to_vec(document)?;
         self.linker.link(Resource::buffer(
             bytes.strip_prefix(b"---\n").unwrap_or(&bytes),
             Path::new("subgraph.yaml"),
         ))
     }
+
+    /// Bundles the manifest and every file it references into a single
+    /// content-addressed directory. See [`Linker::finalize`].
+    fn finalize(&self) -> Result<Cid> {
+        self.linker.finalize()
+    }
 }
 
 #[cfg(test)]
@@ -188,6 +324,26 @@ mod tests {
             manifest.data.data_sources[1].mapping.file,
             Path::new("vendored_mapping.wasm"),
         );
+
+        // `templates` deserializes the same way `dataSources` does.
+        assert_eq!(manifest.data.templates.len(), 1);
+        assert_eq!(manifest.data.templates[0].mapping.api_version, "0.0.4");
+        assert_eq!(
+            manifest.data.templates[0].mapping.file,
+            Path::new("my-subgraph"),
+        );
+        assert_eq!(
+            manifest.data.templates[0].mapping.abis[0].file,
+            Path::new("MyContract.abi"),
+        );
+
+        // Keys this module doesn't model, like `graft`, are preserved
+        // untouched in the raw document.
+        assert_eq!(
+            manifest.document["graft"]["base"].as_str(),
+            Some("QmaQAprjTjqCLfmBDxWEat7V2hdBxNeC36Rj2WBDTdhNdg"),
+        );
+        assert_eq!(manifest.document["graft"]["block"].as_i64(), Some(1234567));
     }
 
     #[test]
@@ -2,11 +2,15 @@ use subgraph::log;
 
 #[export_name = "greatOnTurning"]
 pub extern "C" fn great_on_turning(event: *const ()) {
-    log::info!("[greatOnTurning] Hello from Rust 🦀!");
-    todo!("event pointer: {:?}", event);
+    subgraph::arena::handler(|| {
+        log::info!("[greatOnTurning] Hello from Rust 🦀!");
+        todo!("event pointer: {:?}", event);
+    })
 }
 
 #[export_name = "dayOfTheAnswer"]
 pub extern "C" fn day_of_the_answer(_: *const ()) {
-    todo!();
+    subgraph::arena::handler(|| {
+        todo!();
+    })
 }
@@ -0,0 +1,185 @@
+//! A thread-local bump arena used to satisfy the host's `memory.allocate`
+//! requests, and to hold copies of host-read data, for the duration of a
+//! single exported handler invocation.
+//!
+//! The host copies data (strings, `BigInt`s, entity field maps, ...) into
+//! this module through `memory.allocate` before every import call that
+//! returns data. Since that data is only ever needed for the duration of the
+//! handler that requested it, routing it through a bump arena instead of the
+//! global allocator means handlers that only read host data - the common
+//! case - incur zero per-call heap allocations. Call [`checkpoint`] before
+//! dispatching to an exported handler and [`reset`] with the result once it
+//! returns, to reclaim everything it allocated.
+
+use std::{cell::RefCell, ptr};
+
+/// A point in the arena's allocation history, returned by [`checkpoint`] and
+/// consumed by [`reset`].
+#[derive(Clone, Copy)]
+pub struct Checkpoint(usize);
+
+/// Takes a checkpoint of the arena's current state.
+///
+/// Call this before dispatching to an exported handler, and pass the result
+/// to [`reset`] once the handler returns.
+pub fn checkpoint() -> Checkpoint {
+    ARENA.with(|arena| Checkpoint(arena.borrow().used))
+}
+
+/// Reclaims every allocation made since `checkpoint` was taken.
+pub fn reset(checkpoint: Checkpoint) {
+    ARENA.with(|arena| arena.borrow_mut().used = checkpoint.0);
+}
+
+/// Runs `f`, then reclaims every arena allocation it made.
+///
+/// Every exported mapping handler should wrap its body in this, so that the
+/// host-arena-allocated data it reads (entity field maps, `BigInt`s,
+/// strings, ...) doesn't accumulate for the lifetime of the module
+/// instance:
+///
+/// ```ignore
+/// #[export_name = "myHandler"]
+/// pub extern "C" fn my_handler(event: *const ()) {
+///     subgraph::arena::handler(|| {
+///         // ...
+///     })
+/// }
+/// ```
+pub fn handler<T>(f: impl FnOnce() -> T) -> T {
+    let mark = checkpoint();
+    let result = f();
+    reset(mark);
+    result
+}
+
+/// Allocates `size` bytes aligned to `align` from the arena.
+///
+/// Returns a null pointer if the arena can't satisfy the request without
+/// invalidating allocations made since the last [`reset`]; callers should
+/// fall back to the global allocator in that case.
+pub(crate) fn alloc(size: usize, align: usize) -> *mut u8 {
+    ARENA.with(|arena| arena.borrow_mut().alloc(size, align))
+}
+
+/// Returns whether `ptr` points into the arena's current backing storage.
+pub(crate) fn contains(ptr: *mut u8) -> bool {
+    ARENA.with(|arena| arena.borrow().contains(ptr))
+}
+
+/// The size, in bytes, to reserve for the arena's backing storage the first
+/// time it is grown.
+const DEFAULT_CAPACITY: usize = 16 * 1024;
+
+struct Arena {
+    buf: Vec<u8>,
+    used: usize,
+}
+
+impl Arena {
+    const fn new() -> Self {
+        Self {
+            buf: Vec::new(),
+            used: 0,
+        }
+    }
+
+    fn alloc(&mut self, size: usize, align: usize) -> *mut u8 {
+        let start = align_up(self.used, align);
+        let end = match start.checked_add(size) {
+            Some(end) => end,
+            None => return ptr::null_mut(),
+        };
+
+        if end > self.buf.capacity() {
+            // NOTE: Growing the backing `Vec` can move it, invalidating any
+            // allocation made since the last reset that is still referenced
+            // (e.g. by the host). That is only safe when the arena is empty,
+            // i.e. there is nothing live to invalidate; otherwise, signal the
+            // caller to fall back to the global allocator for this request.
+            if self.used != 0 {
+                return ptr::null_mut();
+            }
+            self.buf = Vec::with_capacity(end.max(DEFAULT_CAPACITY));
+        }
+
+        if end > self.buf.len() {
+            self.buf.resize(end, 0);
+        }
+        self.used = end;
+
+        // SAFETY: `start + size == end <= self.buf.len()`.
+        unsafe { self.buf.as_mut_ptr().add(start) }
+    }
+
+    fn contains(&self, ptr: *mut u8) -> bool {
+        let base = self.buf.as_ptr() as usize;
+        (ptr as usize).wrapping_sub(base) < self.buf.capacity()
+    }
+}
+
+/// Rounds `value` up to the nearest multiple of `align`, which must be a
+/// power of two.
+fn align_up(value: usize, align: usize) -> usize {
+    (value + align - 1) & !(align - 1)
+}
+
+thread_local! {
+    static ARENA: RefCell<Arena> = RefCell::new(Arena::new());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bump_allocates_sequentially() {
+        reset(Checkpoint(0));
+        let mark = checkpoint();
+
+        let a = alloc(4, 4);
+        let b = alloc(4, 4);
+        assert!(!a.is_null() && !b.is_null());
+        assert_eq!(b as usize - a as usize, 4);
+
+        reset(mark);
+    }
+
+    #[test]
+    fn reset_reclaims_allocations() {
+        reset(Checkpoint(0));
+        let mark = checkpoint();
+
+        alloc(64, 8);
+        reset(mark);
+
+        assert_eq!(checkpoint().0, mark.0);
+    }
+
+    #[test]
+    fn contains_reports_arena_allocations() {
+        reset(Checkpoint(0));
+        let mark = checkpoint();
+
+        let ptr = alloc(8, 8);
+        assert!(contains(ptr));
+        assert!(!contains(ptr::null_mut()));
+
+        reset(mark);
+    }
+
+    #[test]
+    fn handler_reclaims_allocations_made_by_f() {
+        reset(Checkpoint(0));
+        let mark = checkpoint();
+
+        let ptr = handler(|| alloc(8, 8));
+        assert!(contains(ptr));
+        assert_eq!(checkpoint().0, mark.0);
+    }
+
+    #[test]
+    fn handler_returns_fs_result() {
+        assert_eq!(handler(|| 42), 42);
+    }
+}
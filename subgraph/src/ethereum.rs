@@ -0,0 +1,347 @@
+//! Decoded Ethereum ABI values, and the event/call parameter lists built
+//! from them.
+//!
+//! The host passes an exported mapping handler a pointer to an
+//! AssemblyScript `ethereum.Event`/`ethereum.Call` object. This module
+//! mirrors that object's layout with `#[repr(C)]` structs (see the
+//! [`crate::ffi`] module for why this is necessary), then eagerly clones it
+//! into owned, typed [`Value`]s the same way [`crate::host`] clones other
+//! host-allocated data before making further host calls.
+//!
+//! `ethereum.Event.parameters`/`ethereum.Call.inputs` are already merged by
+//! the host from indexed log topics and call data into a single
+//! ABI-ordered list, so there's no indexed-vs-data distinction left to make
+//! on this side of the FFI boundary.
+
+use crate::{
+    ffi::{
+        array::{AscArrayBuf, AscTypedArray},
+        string::AscStr,
+    },
+    num::bigint::BigInt,
+};
+
+/// The host's `ethereum.ValueKind` discriminant.
+#[repr(u32)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum ValueKind {
+    Address = 0,
+    FixedBytes = 1,
+    Bytes = 2,
+    Int = 3,
+    Uint = 4,
+    Bool = 5,
+    String = 6,
+    FixedArray = 7,
+    Array = 8,
+    Tuple = 9,
+}
+
+/// The host's `ethereum.Value`: a `kind` discriminant paired with a
+/// `payload` that's either packed inline (`Bool`) or a pointer into further
+/// host-arena-allocated memory (every other kind).
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct AscValue {
+    kind: ValueKind,
+    payload: u64,
+}
+
+impl AscValue {
+    /// # Safety
+    /// `self.payload` must be a valid pointer of the kind implied by
+    /// `self.kind`, as documented on [`AscValue`].
+    unsafe fn decode(&self) -> Value {
+        match self.kind {
+            ValueKind::Address => Value::Address(
+                (*(self.payload as *const AscArrayBuf))
+                    .as_slice()
+                    .try_into()
+                    .expect("address value is not 20 bytes"),
+            ),
+            ValueKind::FixedBytes => {
+                Value::FixedBytes((*(self.payload as *const AscArrayBuf)).as_slice().to_vec())
+            }
+            ValueKind::Bytes => {
+                Value::Bytes((*(self.payload as *const AscArrayBuf)).as_slice().to_vec())
+            }
+            ValueKind::Int => Value::Int(BigInt::from_signed_bytes_le(
+                (*(self.payload as *const AscArrayBuf)).as_slice(),
+            )),
+            ValueKind::Uint => Value::Uint(BigInt::from_unsigned_bytes_le(
+                (*(self.payload as *const AscArrayBuf)).as_slice(),
+            )),
+            ValueKind::Bool => Value::Bool(self.payload != 0),
+            ValueKind::String => Value::String(
+                (*(self.payload as *const AscStr))
+                    .to_string()
+                    .expect("string value is not valid UTF-16"),
+            ),
+            ValueKind::FixedArray | ValueKind::Array => Value::Array(decode_values(self.payload)),
+            ValueKind::Tuple => Value::Tuple(decode_values(self.payload)),
+        }
+    }
+}
+
+/// # Safety
+/// `payload` must point to a valid `AscTypedArray<AscValue>`.
+unsafe fn decode_values(payload: u64) -> Vec<Value> {
+    (*(payload as *const AscTypedArray<'_, AscValue>))
+        .as_slice()
+        .iter()
+        .map(|value| value.decode())
+        .collect()
+}
+
+/// A decoded Ethereum ABI value, owned by the Rust side of the FFI
+/// boundary.
+///
+/// The `into_*` accessors below consume the value and panic if called on
+/// the wrong variant, mirroring the host's own `ethereum.Value.toAddress()`
+/// style API: generated bindings (see `cargo subgraph abigen`) call these
+/// with the accessor matching the ABI type, so a mismatch means the ABI
+/// used to generate the binding no longer matches the contract.
+#[derive(Debug, PartialEq)]
+pub enum Value {
+    Address([u8; 20]),
+    FixedBytes(Vec<u8>),
+    Bytes(Vec<u8>),
+    Int(BigInt),
+    Uint(BigInt),
+    Bool(bool),
+    String(String),
+    Array(Vec<Value>),
+    Tuple(Vec<Value>),
+}
+
+impl Value {
+    pub fn into_address(self) -> [u8; 20] {
+        match self {
+            Value::Address(address) => address,
+            _ => panic!("value is not an address"),
+        }
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        match self {
+            Value::FixedBytes(bytes) | Value::Bytes(bytes) => bytes,
+            _ => panic!("value is not bytes"),
+        }
+    }
+
+    pub fn into_big_int(self) -> BigInt {
+        match self {
+            Value::Int(int) | Value::Uint(int) => int,
+            _ => panic!("value is not an integer"),
+        }
+    }
+
+    pub fn into_bool(self) -> bool {
+        match self {
+            Value::Bool(b) => b,
+            _ => panic!("value is not a bool"),
+        }
+    }
+
+    pub fn into_string(self) -> String {
+        match self {
+            Value::String(s) => s,
+            _ => panic!("value is not a string"),
+        }
+    }
+
+    /// Returns the elements of an array, fixed-size array or tuple value.
+    pub fn into_array(self) -> Vec<Value> {
+        match self {
+            Value::Array(values) | Value::Tuple(values) => values,
+            _ => panic!("value is not an array or tuple"),
+        }
+    }
+}
+
+/// The host's `ethereum.EventParam`: a parameter name paired with its
+/// decoded value.
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct AscEventParam {
+    name: *const AscStr,
+    value: *const AscValue,
+}
+
+/// A named, decoded event or call parameter.
+pub struct Param {
+    pub name: String,
+    pub value: Value,
+}
+
+/// The host's `ethereum.Event`: the address of the contract that emitted
+/// it, and its ABI-ordered, already topic/data-merged parameters.
+#[repr(C)]
+struct AscEvent {
+    address: *const AscArrayBuf,
+    parameters: AscTypedArray<'static, AscEventParam>,
+}
+
+/// A decoded Ethereum event, passed by the host to an exported event
+/// handler.
+pub struct Event {
+    pub address: [u8; 20],
+    pub parameters: Vec<Param>,
+}
+
+impl Event {
+    /// Decodes an [`Event`] from a host-provided `ethereum.Event` pointer.
+    ///
+    /// # Safety
+    /// `ptr` must point to a valid, host-allocated `ethereum.Event`.
+    pub unsafe fn from_raw(ptr: *const ()) -> Self {
+        let event = &*(ptr as *const AscEvent);
+        Self {
+            address: (*event.address)
+                .as_slice()
+                .try_into()
+                .expect("event address is not 20 bytes"),
+            parameters: decode_params(&event.parameters),
+        }
+    }
+}
+
+/// The host's `ethereum.Call`: the address of the contract being called,
+/// and its ABI-ordered, decoded input parameters.
+#[repr(C)]
+struct AscCall {
+    to: *const AscArrayBuf,
+    inputs: AscTypedArray<'static, AscEventParam>,
+}
+
+/// A decoded Ethereum call, passed by the host to an exported call handler.
+pub struct Call {
+    pub to: [u8; 20],
+    pub inputs: Vec<Param>,
+}
+
+impl Call {
+    /// Decodes a [`Call`] from a host-provided `ethereum.Call` pointer.
+    ///
+    /// # Safety
+    /// `ptr` must point to a valid, host-allocated `ethereum.Call`.
+    pub unsafe fn from_raw(ptr: *const ()) -> Self {
+        let call = &*(ptr as *const AscCall);
+        Self {
+            to: (*call.to)
+                .as_slice()
+                .try_into()
+                .expect("call address is not 20 bytes"),
+            inputs: decode_params(&call.inputs),
+        }
+    }
+}
+
+/// # Safety
+/// `params` must be a valid `AscTypedArray<AscEventParam>` whose elements'
+/// `name`/`value` pointers are all valid.
+unsafe fn decode_params(params: &AscTypedArray<'static, AscEventParam>) -> Vec<Param> {
+    params
+        .as_slice()
+        .iter()
+        .map(|param| Param {
+            name: (*param.name)
+                .to_string()
+                .expect("parameter name is not valid UTF-16"),
+            value: (*param.value).decode(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ffi::{
+        array::{AscArrayBuffer, AscTypedBuffer},
+        string::AscString,
+    };
+
+    fn address_value(address: [u8; 20]) -> AscValue {
+        let buf = Box::leak(AscArrayBuffer::new(address));
+        AscValue {
+            kind: ValueKind::Address,
+            payload: buf.as_buf_ptr() as u64,
+        }
+    }
+
+    fn uint_value(n: u8) -> AscValue {
+        let buf = Box::leak(AscArrayBuffer::new([n]));
+        AscValue {
+            kind: ValueKind::Uint,
+            payload: buf.as_buf_ptr() as u64,
+        }
+    }
+
+    fn bool_value(b: bool) -> AscValue {
+        AscValue {
+            kind: ValueKind::Bool,
+            payload: b as u64,
+        }
+    }
+
+    fn string_value(s: &str) -> AscValue {
+        let s = Box::leak(Box::new(AscString::new(s)));
+        AscValue {
+            kind: ValueKind::String,
+            payload: (s.as_asc_str() as *const AscStr) as u64,
+        }
+    }
+
+    fn array_value(elements: Vec<AscValue>) -> AscValue {
+        let buffer = Box::leak(AscTypedBuffer::<AscValue>::new(elements));
+        let array = Box::leak(Box::new(AscTypedArray::new(buffer.as_buf())));
+        AscValue {
+            kind: ValueKind::Array,
+            payload: (&*array as *const AscTypedArray<'_, AscValue>) as u64,
+        }
+    }
+
+    #[test]
+    fn decodes_scalar_values() {
+        let address = [0x11; 20];
+        assert_eq!(
+            unsafe { address_value(address).decode() },
+            Value::Address(address),
+        );
+        assert_eq!(
+            unsafe { uint_value(42).decode() }.into_big_int(),
+            BigInt::from_unsigned_bytes_le([42]),
+        );
+        // A `uint` whose top byte has its high bit set must still decode as
+        // positive: it's not a twos-complement `int`.
+        assert_eq!(
+            unsafe { uint_value(0xff).decode() }.into_big_int(),
+            BigInt::from_unsigned_bytes_le([0xff]),
+        );
+        assert_eq!(unsafe { bool_value(true).decode() }, Value::Bool(true));
+        assert_eq!(
+            unsafe { string_value("hello").decode() },
+            Value::String("hello".to_owned()),
+        );
+    }
+
+    #[test]
+    fn decodes_array_values() {
+        let value = unsafe { array_value(vec![uint_value(1), uint_value(2)]).decode() };
+        let mut elements = value.into_array().into_iter();
+        assert_eq!(
+            elements.next().unwrap().into_big_int(),
+            BigInt::from_unsigned_bytes_le([1]),
+        );
+        assert_eq!(
+            elements.next().unwrap().into_big_int(),
+            BigInt::from_unsigned_bytes_le([2]),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "value is not an address")]
+    fn accessor_panics_on_wrong_variant() {
+        unsafe { bool_value(true).decode() }.into_address();
+    }
+}
@@ -15,6 +15,9 @@
 //!
 //! Data from host functions that return references or pointers must be cloned
 //! into Rust-owned memory before any futher host allocations occur.
+//!
+//! Cloning doesn't necessarily mean heap-allocating: see [`crate::arena`] for
+//! the handler-scoped arena that most of this cloning draws from instead.
 
 pub mod array;
 mod buffer;
@@ -9,17 +9,32 @@ pub type AscArrayBuffer = AscBuffer<u8, u64>;
 /// An borrowed AssemblyScript array buffer.
 pub type AscArrayBuf = AscBuf<u8, u64>;
 
-/// A `u8` typed array that slices an array buffer.
+/// An owned, 8-byte-aligned AssemblyScript buffer for an arbitrary `Copy`
+/// element type `T`, backing an [`AscTypedArray<T>`] view.
+pub type AscTypedBuffer<T> = AscBuffer<T, u64>;
+
+/// A typed array that slices an array buffer, parameterized over its element
+/// type `T`. AssemblyScript `ArrayBufferView`s (`Uint8Array`, `Int32Array`,
+/// `Float64Array`, etc.) are all laid out this way, regardless of `T`'s
+/// width: a reference to the backing buffer, followed by an element offset
+/// and length into it. The backing buffer itself is always 8-byte aligned,
+/// hence the fixed `u64` alignment on `AscBuf` here.
 #[repr(C)]
-pub struct AscUint8Array<'a> {
-    buffer: &'a AscArrayBuf,
+pub struct AscTypedArray<'a, T> {
+    buffer: &'a AscBuf<T, u64>,
     offset: usize,
     len: usize,
 }
 
-impl<'a> AscUint8Array<'a> {
+/// A `u8` typed array that slices an array buffer.
+pub type AscUint8Array<'a> = AscTypedArray<'a, u8>;
+
+impl<'a, T> AscTypedArray<'a, T>
+where
+    T: Copy + 'static,
+{
     /// Creates a typed array view over the entire specifed array buffer.
-    pub fn new(buffer: &'a AscArrayBuf) -> Self {
+    pub fn new(buffer: &'a AscBuf<T, u64>) -> Self {
         Self {
             buffer,
             offset: 0,
@@ -27,21 +42,48 @@ impl<'a> AscUint8Array<'a> {
         }
     }
 
-    /// Returns the `u8` typed array as a Rust slice.
-    pub fn as_bytes(&self) -> &'a [u8] {
+    /// Returns the number of elements in the typed array.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the length of the typed array in bytes.
+    pub fn byte_length(&self) -> usize {
+        self.len * std::mem::size_of::<T>()
+    }
+
+    /// Returns the typed array as a Rust slice.
+    pub fn as_slice(&self) -> &'a [T] {
         &self.buffer.as_slice()[self.offset..(self.offset + self.len)]
     }
 
-    /// Creates an owned AssemblyScript array buffer from the sliced bytes.
-    pub fn to_array_buffer(&self) -> Cow<'a, AscArrayBuf> {
+    /// Creates an AssemblyScript array buffer from the sliced elements.
+    ///
+    /// When the array spans the entire underlying buffer, this borrows it
+    /// directly with no copy. Otherwise, the sliced elements are copied into
+    /// the handler-scoped arena (see [`crate::arena`]) rather than the heap,
+    /// so the copy is reclaimed in bulk once the handler returns instead of
+    /// requiring an owned buffer to be dropped.
+    pub fn to_array_buffer(&self) -> Cow<'a, AscBuf<T, u64>> {
         if self.offset == 0 && self.len == self.buffer.len() {
             Cow::Borrowed(self.buffer)
         } else {
-            Cow::Owned(AscArrayBuffer::new(self.as_bytes()))
+            Cow::Borrowed(crate::ffi::buffer::copy_to_arena(self.as_slice()))
         }
     }
 }
 
+impl<'a> AscUint8Array<'a> {
+    /// Returns the `u8` typed array as a Rust slice.
+    ///
+    /// This is a byte-oriented alias for [`AscTypedArray::as_slice`], kept
+    /// around since most existing callers deal in bytes rather than a
+    /// generic element type.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.as_slice()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -56,4 +98,45 @@ mod tests {
             Layout::new::<(usize, [u64; 0], [u8; 3])>().pad_to_align(),
         );
     }
+
+    #[test]
+    fn array_buffer_layout_i32_elements() {
+        let buffer = AscBuffer::<i32, u64>::new([1, 2, 3]);
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(
+            Layout::for_value(&*buffer),
+            Layout::new::<(usize, [u64; 0], [i32; 3])>().pad_to_align(),
+        );
+    }
+
+    #[test]
+    fn array_buffer_layout_f64_elements() {
+        let buffer = AscBuffer::<f64, u64>::new([1.0, 2.0]);
+        assert_eq!(buffer.len(), 2);
+        assert_eq!(
+            Layout::for_value(&*buffer),
+            Layout::new::<(usize, [u64; 0], [f64; 2])>().pad_to_align(),
+        );
+    }
+
+    #[test]
+    fn to_array_buffer_borrows_whole_buffer() {
+        let buffer = AscArrayBuffer::new(b"\x2a\x2a");
+        let array = AscUint8Array::new(&buffer);
+
+        assert!(matches!(array.to_array_buffer(), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn to_array_buffer_copies_partial_slice() {
+        let buffer = AscArrayBuffer::new(b"\x01\x02\x03\x04");
+        let array = AscUint8Array {
+            buffer: &buffer,
+            offset: 1,
+            len: 2,
+        };
+
+        let copy = array.to_array_buffer();
+        assert_eq!(copy.as_slice(), [0x02, 0x03]);
+    }
 }
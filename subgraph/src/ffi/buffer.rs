@@ -3,6 +3,8 @@
 use std::{
     alloc::{self, Layout, LayoutError},
     borrow::{Borrow, ToOwned},
+    convert::Infallible,
+    error::Error,
     fmt::{self, Debug, Formatter},
     mem::{self, MaybeUninit},
     ops::Deref,
@@ -103,6 +105,81 @@ impl<T, A> AscBuffer<T, A> {
         }
     }
 
+    /// Creates a new AssemblyScript buffer of the specified length, calling
+    /// `f` to compute each element in place.
+    ///
+    /// Unlike [`AscBuffer::new`], this doesn't require a pre-existing slice
+    /// to copy from, so it works for element types that aren't `Copy`.
+    pub fn from_fn(len: usize, mut f: impl FnMut(usize) -> T) -> Box<Self> {
+        match Self::try_from_fn::<Infallible>(len, |index| Ok(f(index))) {
+            Ok(buffer) => buffer,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Like [`AscBuffer::from_fn`], but `f` may fail. If it does, only the
+    /// already-initialized prefix of the buffer is dropped before the
+    /// allocation itself is freed.
+    pub fn try_from_fn<E>(len: usize, mut f: impl FnMut(usize) -> Result<T, E>) -> Result<Box<Self>, E> {
+        // SAFETY: `buffer` is uninitialized until the loop below fills every
+        // element; `guard` makes sure that, if `f` returns `Err` or panics
+        // partway through, only the elements that were actually written get
+        // dropped.
+        unsafe {
+            let mut buffer = alloc_buffer::<T, A>(len);
+            buffer.inner.len = len;
+
+            struct Guard<T> {
+                ptr: *mut T,
+                initialized: usize,
+            }
+
+            impl<T> Drop for Guard<T> {
+                fn drop(&mut self) {
+                    // SAFETY: `ptr` points at the start of the buffer and the
+                    // first `initialized` elements have been written to.
+                    unsafe {
+                        ptr::drop_in_place(slice::from_raw_parts_mut(self.ptr, self.initialized));
+                    }
+                }
+            }
+
+            let mut guard = Guard {
+                ptr: buffer.inner.buf.as_mut_ptr().cast::<T>(),
+                initialized: 0,
+            };
+
+            for index in 0..len {
+                let value = match f(index) {
+                    Ok(value) => value,
+                    Err(err) => return Err(err),
+                };
+                buffer.inner.buf[index].write(value);
+                guard.initialized += 1;
+            }
+
+            // Every element was initialized above, so there is nothing left
+            // for `guard` to clean up; `MaybeUninit<T>` has no drop glue of
+            // its own, so `buffer` can simply be reinterpreted as fully
+            // initialized.
+            mem::forget(guard);
+            Ok(mem::transmute(buffer))
+        }
+    }
+
+    /// Creates a new AssemblyScript buffer from an [`ExactSizeIterator`],
+    /// writing each element in place as it's produced.
+    pub fn try_from_iter<I>(iter: I) -> Result<Box<Self>, IncompleteIteratorError>
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let mut iter = iter.into_iter();
+        let len = iter.len();
+
+        Self::try_from_fn(len, |_| iter.next().ok_or(IncompleteIteratorError))
+    }
+
     /// Returns a reference to a borrowed AssemblyScript buffer.
     pub fn as_buf(&self) -> &AscBuf<T, A> {
         unsafe { &*(&self.inner.len as *const usize).cast::<AscBuf<T, A>>() }
@@ -114,6 +191,19 @@ impl<T, A> AscBuffer<T, A> {
     }
 }
 
+/// The iterator passed to [`AscBuffer::try_from_iter`] produced fewer
+/// elements than its reported [`ExactSizeIterator::len`].
+#[derive(Debug)]
+pub struct IncompleteIteratorError;
+
+impl fmt::Display for IncompleteIteratorError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("iterator ended before producing its reported length")
+    }
+}
+
+impl Error for IncompleteIteratorError {}
+
 impl<T, A> Borrow<AscBuf<T, A>> for Box<AscBuffer<T, A>> {
     fn borrow(&self) -> &AscBuf<T, A> {
         self.as_buf()
@@ -140,10 +230,62 @@ where
 /// Returns the memory layout for an AssemblyScript buffer with the specified
 /// dynamic length.
 fn buffer_layout<T, A>(len: usize) -> Result<Layout, LayoutError> {
-    let (layout, _) = Layout::new::<AscBuf<T, A>>().extend(Layout::array::<T>(len)?)?;
+    Ok(buffer_layout_with_offset::<T, A>(len)?.0)
+}
+
+/// Like [`buffer_layout`], but also returns the byte offset at which the
+/// buffer's elements start.
+fn buffer_layout_with_offset<T, A>(len: usize) -> Result<(Layout, usize), LayoutError> {
+    let (layout, offset) = Layout::new::<AscBuf<T, A>>().extend(Layout::array::<T>(len)?)?;
     // NOTE: Pad to alignment for C ABI compatibility. See
     // <https://doc.rust-lang.org/std/alloc/struct.Layout.html#method.extend>
-    Ok(layout.pad_to_align())
+    Ok((layout.pad_to_align(), offset))
+}
+
+/// Copies `slice` into the handler-scoped arena (see [`crate::arena`]),
+/// falling back to the heap if the arena can't satisfy the request, and
+/// returns a thin reference to the copy.
+///
+/// Unlike [`AscBuffer::new`], this never needs an owning `Box` to be dropped:
+/// arena allocations are reclaimed in bulk when the arena resets, and heap
+/// fallback allocations are freed through `exports::deallocate` once the
+/// host is done with them.
+pub(crate) fn copy_to_arena<T, A>(slice: &[T]) -> &'static AscBuf<T, A> {
+    let layout = buffer_layout::<T, A>(slice.len())
+        .expect("attempted to allocate a buffer that is larger than the address space.");
+
+    let dst = crate::arena::alloc(layout.size(), layout.align());
+    let dst = if dst.is_null() {
+        // SAFETY: `layout` always has a non-zero size, since it includes at
+        // least the buffer header.
+        unsafe { alloc::alloc(layout) }
+    } else {
+        dst
+    };
+
+    // SAFETY: `dst` points to a fresh allocation of at least `layout`'s size
+    // and alignment, sourced either from the arena or the global allocator
+    // above, and is only ever read through the returned `'static` reference
+    // by code that, per the `crate::arena` contract, doesn't outlive the
+    // next arena reset.
+    unsafe { write_buf(dst, slice) }
+}
+
+/// Writes `slice`'s elements into `dst`, laid out the same way as an
+/// [`AscBuffer`], and returns a thin reference to the result.
+///
+/// # Safety
+///
+/// `dst` must be valid for reads and writes for `buffer_layout::<T,
+/// A>(slice.len())`.
+unsafe fn write_buf<'a, T, A>(dst: *mut u8, slice: &[T]) -> &'a AscBuf<T, A> {
+    let (_, offset) = buffer_layout_with_offset::<T, A>(slice.len())
+        .expect("attempted to allocate a buffer that is larger than the address space.");
+
+    (dst as *mut usize).write(slice.len());
+    ptr::copy_nonoverlapping(slice.as_ptr(), dst.add(offset).cast(), slice.len());
+
+    &*(dst as *const AscBuf<T, A>)
 }
 
 /// A Rust dynamically sized type fat pointer.
@@ -277,4 +419,56 @@ mod tests {
     fn ptr_offset<T, U>(x: &T, y: &U) -> isize {
         ((y as *const U) as isize) - ((x as *const T) as isize)
     }
+
+    #[test]
+    fn from_fn_initializes_each_element() {
+        let buffer = AscBuffer::<String, usize>::from_fn(3, |i| i.to_string());
+        assert_eq!(buffer.as_slice(), ["0", "1", "2"]);
+    }
+
+    #[test]
+    fn try_from_fn_drops_initialized_prefix_on_error() {
+        use std::rc::Rc;
+
+        let dropped = Rc::new(());
+        let result = AscBuffer::<Rc<()>, usize>::try_from_fn(3, |i| {
+            if i == 2 {
+                Err(())
+            } else {
+                Ok(dropped.clone())
+            }
+        });
+
+        assert!(result.is_err());
+        // Only `dropped` itself (the local variable) should still be held;
+        // the two clones written into the buffer must have been dropped.
+        assert_eq!(Rc::strong_count(&dropped), 1);
+    }
+
+    #[test]
+    fn try_from_iter_builds_buffer_from_exact_size_iterator() {
+        let buffer = AscBuffer::<u32, usize>::try_from_iter(vec![10, 20, 30]).unwrap();
+        assert_eq!(buffer.as_slice(), [10, 20, 30]);
+    }
+
+    #[test]
+    fn try_from_iter_reports_incomplete_iterators() {
+        struct LiarIter(usize);
+
+        impl Iterator for LiarIter {
+            type Item = u32;
+
+            fn next(&mut self) -> Option<u32> {
+                None
+            }
+        }
+
+        impl ExactSizeIterator for LiarIter {
+            fn len(&self) -> usize {
+                self.0
+            }
+        }
+
+        assert!(AscBuffer::<u32, usize>::try_from_iter(LiarIter(3)).is_err());
+    }
 }
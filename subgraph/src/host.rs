@@ -0,0 +1,236 @@
+//! Safe wrappers around the raw Graph host import functions declared in
+//! [`crate::sys`].
+//!
+//! Mappings should prefer these functions over calling into [`crate::sys`]
+//! directly, as they take care of the `unsafe` FFI calls and of cloning any
+//! host-allocated return data into Rust-owned memory (see the [`crate::ffi`]
+//! module documentation for why this is important).
+
+use crate::{
+    ffi::{
+        array::{AscArrayBuf, AscArrayBuffer},
+        string::{AscStr, AscString},
+    },
+    num::bigint::BigInt,
+    sys,
+};
+
+/// Invokes a smart contract function through the host.
+///
+/// `call` is a host-defined encoding of the contract address, ABI, function
+/// signature and parameters. Returns `None` if the call reverted.
+pub fn ethereum_call(call: impl AsRef<[u8]>) -> Option<Vec<u8>> {
+    let call = AscArrayBuffer::new(call.as_ref());
+
+    // SAFETY: The host allocation gets cloned to owned memory before any
+    // further host calls are made.
+    copy_bytes_opt(unsafe { sys::ethereum::call(&call) })
+}
+
+/// Entity store functions.
+pub mod store {
+    use super::*;
+
+    /// Loads an entity's field values by type and id, or `None` if no such
+    /// entity exists.
+    pub fn get(entity: &str, id: &str) -> Option<Vec<u8>> {
+        let entity = AscString::new(entity);
+        let id = AscString::new(id);
+
+        // SAFETY: The host allocation gets cloned to owned memory before any
+        // further host calls are made.
+        copy_bytes_opt(unsafe { sys::store::get(&entity, &id) })
+    }
+
+    /// Sets an entity's field values by type and id.
+    pub fn set(entity: &str, id: &str, data: impl AsRef<[u8]>) {
+        let entity = AscString::new(entity);
+        let id = AscString::new(id);
+        let data = AscArrayBuffer::new(data.as_ref());
+
+        unsafe { sys::store::set(&entity, &id, &data) }
+    }
+
+    /// Removes an entity by type and id.
+    pub fn remove(entity: &str, id: &str) {
+        let entity = AscString::new(entity);
+        let id = AscString::new(id);
+
+        unsafe { sys::store::remove(&entity, &id) }
+    }
+}
+
+/// IPFS access functions.
+pub mod ipfs {
+    use super::*;
+
+    /// Reads the complete contents of the file behind an IPFS hash or path.
+    pub fn cat(hash: &str) -> Option<Vec<u8>> {
+        let hash = AscString::new(hash);
+
+        // SAFETY: The host allocation gets cloned to owned memory before any
+        // further host calls are made.
+        copy_bytes_opt(unsafe { sys::ipfs::cat(&hash) })
+    }
+
+    /// Streams newline-delimited JSON values from the file behind an IPFS
+    /// hash or path to a mapping callback named `callback`. `user_data` and
+    /// `flags` are host-defined encodings of, respectively, extra context
+    /// passed through to the callback and processing flags.
+    pub fn map(hash: &str, callback: &str, user_data: impl AsRef<[u8]>, flags: impl AsRef<[u8]>) {
+        let hash = AscString::new(hash);
+        let callback = AscString::new(callback);
+        let user_data = AscArrayBuffer::new(user_data.as_ref());
+        let flags = AscArrayBuffer::new(flags.as_ref());
+
+        unsafe { sys::ipfs::map(&hash, &callback, &user_data, &flags) }
+    }
+}
+
+/// JSON parsing functions.
+pub mod json {
+    use super::*;
+
+    /// Parses a UTF-8 encoded byte buffer as JSON, returning the host's
+    /// encoding of the resulting value.
+    pub fn from_bytes(data: impl AsRef<[u8]>) -> Vec<u8> {
+        let data = AscArrayBuffer::new(data.as_ref());
+
+        // SAFETY: The host allocation gets cloned to owned memory before any
+        // further host calls are made.
+        copy_bytes(unsafe { sys::json::fromBytes(&data) })
+    }
+
+    /// Converts a host-encoded JSON value into a [`BigInt`].
+    pub fn to_big_int(value: impl AsRef<[u8]>) -> BigInt {
+        let value = AscArrayBuffer::new(value.as_ref());
+
+        // SAFETY: The host allocation gets cloned to owned memory before any
+        // further host calls are made.
+        let inner = unsafe { sys::json::toBigInt(&value) };
+
+        copy_big_int(inner)
+    }
+
+    /// Converts a host-encoded JSON value into an `i64`.
+    pub fn to_i64(value: impl AsRef<[u8]>) -> i64 {
+        let value = AscArrayBuffer::new(value.as_ref());
+
+        unsafe { sys::json::toI64(&value) }
+    }
+}
+
+/// Cryptographic hashing functions.
+pub mod crypto {
+    use super::*;
+
+    /// Hashes a byte buffer using `keccak256`.
+    pub fn keccak256(data: impl AsRef<[u8]>) -> Vec<u8> {
+        let data = AscArrayBuffer::new(data.as_ref());
+
+        // SAFETY: The host allocation gets cloned to owned memory before any
+        // further host calls are made.
+        copy_bytes(unsafe { sys::crypto::keccak256(&data) })
+    }
+}
+
+/// Functions for introspecting the current data source.
+pub mod data_source {
+    use super::*;
+
+    /// Returns the address of the contract that the current data source is
+    /// indexing.
+    pub fn address() -> Vec<u8> {
+        // SAFETY: The host allocation gets cloned to owned memory before any
+        // further host calls are made.
+        copy_bytes(unsafe { sys::dataSource::address() })
+    }
+
+    /// Returns the current data source's context, or `None` if it has none.
+    pub fn context() -> Option<Vec<u8>> {
+        // SAFETY: The host allocation gets cloned to owned memory before any
+        // further host calls are made.
+        copy_bytes_opt(unsafe { sys::dataSource::context() })
+    }
+}
+
+/// Low level type conversion functions.
+pub mod type_conversion {
+    use super::*;
+
+    /// Formats a `BigInt` as a `0x`-prefixed hexadecimal string.
+    pub fn big_int_to_hex(x: &BigInt) -> String {
+        copy_string(unsafe { sys::typeConversion::bigIntToHex(&x.as_host()) })
+    }
+
+    /// Converts an `i32` into a `BigInt`.
+    pub fn i32_to_big_int(x: i32) -> BigInt {
+        copy_big_int(unsafe { sys::typeConversion::i32ToBigInt(x) })
+    }
+
+    /// Converts a `BigInt` into an `i32`, truncating if necessary.
+    pub fn big_int_to_i32(x: &BigInt) -> i32 {
+        unsafe { sys::typeConversion::bigIntToI32(&x.as_host()) }
+    }
+
+    /// Formats a byte buffer as a `0x`-prefixed hexadecimal string.
+    pub fn bytes_to_hex(bytes: impl AsRef<[u8]>) -> String {
+        let bytes = AscArrayBuffer::new(bytes.as_ref());
+        copy_string(unsafe { sys::typeConversion::bytesToHex(&bytes) })
+    }
+
+    /// Interprets a byte buffer as a UTF-8 encoded string.
+    pub fn bytes_to_string(bytes: impl AsRef<[u8]>) -> String {
+        let bytes = AscArrayBuffer::new(bytes.as_ref());
+        copy_string(unsafe { sys::typeConversion::bytesToString(&bytes) })
+    }
+
+    /// Formats a byte buffer as a base58 string.
+    pub fn bytes_to_base58(bytes: impl AsRef<[u8]>) -> String {
+        let bytes = AscArrayBuffer::new(bytes.as_ref());
+        copy_string(unsafe { sys::typeConversion::bytesToBase58(&bytes) })
+    }
+
+    /// Parses a hexadecimal string into a 20-byte `H160` address.
+    pub fn string_to_h160(s: &str) -> Vec<u8> {
+        let s = AscString::new(s);
+
+        // SAFETY: The host allocation gets cloned to owned memory before any
+        // further host calls are made.
+        copy_bytes(unsafe { sys::typeConversion::stringToH160(&s) })
+    }
+}
+
+/// Clones a host-allocated array buffer into an owned byte vector.
+fn copy_bytes(ptr: *mut AscArrayBuf) -> Vec<u8> {
+    // SAFETY: Host functions returning array buffers always return a valid
+    // pointer to host-arena-allocated memory.
+    unsafe { (*ptr).as_slice().to_vec() }
+}
+
+/// Like [`copy_bytes`], but treats a null pointer as `None`.
+fn copy_bytes_opt(ptr: *mut AscArrayBuf) -> Option<Vec<u8>> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(copy_bytes(ptr))
+    }
+}
+
+/// Clones a host-allocated string into an owned `String`.
+fn copy_string(ptr: *mut AscStr) -> String {
+    // SAFETY: Host functions returning strings always return a valid pointer
+    // to host-arena-allocated memory.
+    unsafe {
+        (*ptr)
+            .to_string()
+            .expect("host strings are always valid UTF-16")
+    }
+}
+
+/// Clones a host-allocated `BigInt` into an owned [`BigInt`].
+fn copy_big_int(ptr: *mut sys::BigInt<'static>) -> BigInt {
+    // SAFETY: Host functions returning `BigInt`s always return a valid
+    // pointer to host-arena-allocated memory.
+    BigInt::from_signed_bytes_le(unsafe { (*ptr).as_bytes() })
+}
@@ -2,7 +2,10 @@
 //! Rust 🦀
 
 mod abort;
+pub mod arena;
+pub mod ethereum;
 mod ffi;
+pub mod host;
 mod logger;
 mod num;
 mod sys;
@@ -14,7 +17,7 @@ pub use log;
 #[cfg(target_arch = "wasm32")]
 #[doc(hidden)]
 pub mod exports {
-    use crate::{abort, logger};
+    use crate::{abort, arena, logger};
     use std::{
         alloc::{self, Layout},
         mem, ptr,
@@ -31,12 +34,20 @@ pub mod exports {
 
     /// A hook into the Rust memory allocation function so that the host may
     /// allocate space for data to be sent to the mapping handlers.
+    ///
+    /// This draws from a handler-scoped bump arena (see [`crate::arena`])
+    /// rather than the global allocator, so that the, typically short-lived,
+    /// data copied in by the host for a single handler call doesn't churn
+    /// the allocator. Requests the arena can't satisfy fall back to a normal
+    /// heap allocation, which is freed again through [`deallocate`].
     #[export_name = "memory.allocate"]
     pub extern "C" fn alloc(size: usize) -> *mut u8 {
-        // NOTE: Use the maximum wasm32 alignment by default.
-        const ALIGN: usize = mem::size_of::<u64>();
+        let ptr = arena::alloc(size, ALIGN);
+        if !ptr.is_null() {
+            return ptr;
+        }
 
-        let layout = match Layout::from_size_align(ALIGN, size) {
+        let layout = match Layout::from_size_align(size, ALIGN) {
             Ok(value) => value,
             Err(_) => {
                 // NOTE: Since `ALIGN` is guaranteed to be valid, this can only
@@ -49,6 +60,29 @@ pub mod exports {
         unsafe { alloc::alloc(layout) }
     }
 
+    /// A hook into the Rust memory deallocation function so that the host may
+    /// free space previously allocated through [`alloc`].
+    ///
+    /// Allocations drawn from the handler-scoped arena are a no-op here; they
+    /// are reclaimed in bulk once the arena is reset around the exported
+    /// handler call that requested them.
+    #[export_name = "memory.deallocate"]
+    pub extern "C" fn deallocate(ptr: *mut u8, size: usize) {
+        if arena::contains(ptr) {
+            return;
+        }
+
+        let layout = match Layout::from_size_align(size, ALIGN) {
+            Ok(value) => value,
+            Err(_) => return,
+        };
+
+        unsafe { alloc::dealloc(ptr, layout) }
+    }
+
+    // NOTE: Use the maximum wasm32 alignment for allocations by default.
+    const ALIGN: usize = mem::size_of::<u64>();
+
     #[no_mangle]
     #[link_section = "apiVersion"]
     pub static API_VERSION: [u8; 5] = *b"0.0.4";
@@ -62,4 +96,5 @@ fn unused_exports() {
     #![allow(unused)]
     let _ = abort::set_panic_hook;
     let _ = logger::init;
+    let _ = arena::contains;
 }
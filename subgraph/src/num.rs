@@ -0,0 +1,4 @@
+//! Subgraph arbitrary precision numeric types.
+
+pub mod bigdecimal;
+pub mod bigint;
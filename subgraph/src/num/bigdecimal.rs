@@ -0,0 +1,176 @@
+//! Subgraph arbitrary precision decimal implementation.
+
+use crate::{
+    ffi::{array::AscArrayBuffer, string::AscString},
+    sys,
+};
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    str::FromStr,
+};
+
+/// A arbitrary precision decimal number. This uses the host big decimal
+/// implementation through the provided import functions.
+///
+/// `BigDecimal` is represented on the host the same way as `BigInt`: as its
+/// little-endian bytes.
+pub struct BigDecimal {
+    inner: Box<AscArrayBuffer>,
+}
+
+impl BigDecimal {
+    /// Creates a `BigDecimal` instance from unsigned little endian bytes.
+    pub fn from_unsigned_bytes_le(bytes: impl AsRef<[u8]>) -> Self {
+        let bytes = bytes.as_ref();
+        if matches!(bytes.last(), Some(byte) if byte & 0x80 != 0) {
+            // NOTE: We need to append an extra `0`-byte so that the value isn't
+            // treated as negative.
+            let mut corrected_bytes = Vec::with_capacity(bytes.len() + 1);
+            corrected_bytes.extend_from_slice(bytes);
+            corrected_bytes.push(0);
+            Self::from_signed_bytes_le(&corrected_bytes)
+        } else {
+            Self::from_signed_bytes_le(bytes)
+        }
+    }
+
+    /// Creates a `BigDecimal` instance from signed little endian bytes.
+    pub fn from_signed_bytes_le(bytes: impl AsRef<[u8]>) -> Self {
+        Self {
+            inner: AscArrayBuffer::new(bytes.as_ref()),
+        }
+    }
+
+    /// Add the specified `BigDecimal` to `self`, returning the result.
+    pub fn add(&self, rhs: &Self) -> Self {
+        let (x, y) = (self.as_host(), rhs.as_host());
+
+        // SAFETY: The host allocation gets cloned to an owned array buffer.
+        let inner = unsafe { (*sys::bigDecimal::plus(&x, &y)).to_array_buffer() }.into_owned();
+
+        Self { inner }
+    }
+
+    /// Subtract the specified `BigDecimal` from `self`, returning the result.
+    pub fn sub(&self, rhs: &Self) -> Self {
+        let (x, y) = (self.as_host(), rhs.as_host());
+
+        // SAFETY: The host allocation gets cloned to an owned array buffer.
+        let inner = unsafe { (*sys::bigDecimal::minus(&x, &y)).to_array_buffer() }.into_owned();
+
+        Self { inner }
+    }
+
+    /// Multiply `self` by the specified `BigDecimal`, returning the result.
+    pub fn mul(&self, rhs: &Self) -> Self {
+        let (x, y) = (self.as_host(), rhs.as_host());
+
+        // SAFETY: The host allocation gets cloned to an owned array buffer.
+        let inner = unsafe { (*sys::bigDecimal::times(&x, &y)).to_array_buffer() }.into_owned();
+
+        Self { inner }
+    }
+
+    /// Divide `self` by the specified `BigDecimal`, returning the result.
+    pub fn div(&self, rhs: &Self) -> Self {
+        let (x, y) = (self.as_host(), rhs.as_host());
+
+        // SAFETY: The host allocation gets cloned to an owned array buffer.
+        let inner = unsafe { (*sys::bigDecimal::dividedBy(&x, &y)).to_array_buffer() }.into_owned();
+
+        Self { inner }
+    }
+
+    fn as_host(&self) -> sys::BigDecimal<'_> {
+        sys::BigDecimal::new(&self.inner)
+    }
+}
+
+impl Debug for BigDecimal {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for BigDecimal {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let x = self.as_host();
+        let s = {
+            let asc_str = unsafe { &*sys::bigDecimal::toString(&x) };
+            asc_str
+                .to_string()
+                .expect("decimal strings are always valid UTF-16")
+        };
+
+        f.write_str(&s)
+    }
+}
+
+impl FromStr for BigDecimal {
+    type Err = ParseBigDecimalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseBigDecimalError(()));
+        }
+
+        let source = AscString::new(s);
+
+        // SAFETY: The host allocation gets cloned to an owned array buffer.
+        let inner = unsafe {
+            (*sys::bigDecimal::fromString(source.as_asc_str()))
+                .to_array_buffer()
+                .into_owned()
+        };
+
+        Ok(Self { inner })
+    }
+}
+
+/// An error that can occur parsing a `BigDecimal` from a string.
+#[derive(Debug, Eq, PartialEq)]
+pub struct ParseBigDecimalError(());
+
+impl Display for ParseBigDecimalError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("invalid digit found in string")
+    }
+}
+
+impl std::error::Error for ParseBigDecimalError {}
+
+macro_rules! from_primitive {
+    ($(
+        $m:ident : $($t:ty),* ;
+    )*) => {$($(
+        impl From<$t> for BigDecimal {
+            fn from(x: $t) -> Self {
+                Self::$m(&x.to_le_bytes())
+            }
+        }
+    )*)*};
+}
+
+from_primitive! {
+    from_signed_bytes_le: i8, i16, i32, i64, i128, isize;
+    from_unsigned_bytes_le: u8, u16, u32, u64, u128, usize;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn primitive_conversion() {
+        let x = BigDecimal::from(42u32);
+        assert_eq!(x.inner.as_slice(), [42, 0, 0, 0]);
+
+        let x = BigDecimal::from(-1i32);
+        assert_eq!(x.inner.as_slice(), [0xff, 0xff, 0xff, 0xff]);
+    }
+
+    #[test]
+    fn invalid_decimal_string() {
+        assert!("".parse::<BigDecimal>().is_err());
+    }
+}
@@ -1,7 +1,14 @@
 //! Subgraph arbitrary precision integer implementation.
 
-use crate::{ffi::array::AscArrayBuffer, sys};
-use std::fmt::{self, Debug, Display, Formatter};
+use crate::{
+    ffi::{array::AscArrayBuffer, string::AscString},
+    sys,
+};
+use std::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display, Formatter},
+    str::FromStr,
+};
 
 /// A arbitrary precision big integer. This uses the host big integer
 /// implementation through the provided import functions.
@@ -36,11 +43,100 @@ impl BigInt {
 
     /// Add the specified `BigInt` to `self`, returning the result.
     pub fn add(&self, rhs: &Self) -> Self {
+        let (x, y) = (self.as_host(), rhs.as_host());
+
+        // SAFETY: The host allocation gets cloned to an owned array buffer.
+        let inner = unsafe { (*sys::bigInt::plus(&x, &y)).to_array_buffer() }.into_owned();
+
+        Self { inner }
+    }
+
+    /// Subtract the specified `BigInt` from `self`, returning the result.
+    pub fn sub(&self, rhs: &Self) -> Self {
+        let (x, y) = (self.as_host(), rhs.as_host());
+
+        // SAFETY: The host allocation gets cloned to an owned array buffer.
+        let inner = unsafe { (*sys::bigInt::minus(&x, &y)).to_array_buffer() }.into_owned();
+
+        Self { inner }
+    }
+
+    /// Multiply `self` by the specified `BigInt`, returning the result.
+    pub fn mul(&self, rhs: &Self) -> Self {
+        let (x, y) = (self.as_host(), rhs.as_host());
+
+        // SAFETY: The host allocation gets cloned to an owned array buffer.
+        let inner = unsafe { (*sys::bigInt::times(&x, &y)).to_array_buffer() }.into_owned();
+
+        Self { inner }
+    }
+
+    /// Divide `self` by the specified `BigInt`, returning the result.
+    pub fn div(&self, rhs: &Self) -> Self {
+        let (x, y) = (self.as_host(), rhs.as_host());
+
+        // SAFETY: The host allocation gets cloned to an owned array buffer.
+        let inner = unsafe { (*sys::bigInt::dividedBy(&x, &y)).to_array_buffer() }.into_owned();
+
+        Self { inner }
+    }
+
+    /// Compute `self` modulo the specified `BigInt`, returning the result.
+    pub fn rem(&self, rhs: &Self) -> Self {
+        let (x, y) = (self.as_host(), rhs.as_host());
+
+        // SAFETY: The host allocation gets cloned to an owned array buffer.
+        let inner = unsafe { (*sys::bigInt::r#mod(&x, &y)).to_array_buffer() }.into_owned();
+
+        Self { inner }
+    }
+
+    /// Raise `self` to the specified power, returning the result.
+    pub fn pow(&self, exp: u8) -> Self {
+        let x = self.as_host();
+
+        // SAFETY: The host allocation gets cloned to an owned array buffer.
+        let inner = unsafe { (*sys::bigInt::pow(&x, exp)).to_array_buffer() }.into_owned();
+
+        Self { inner }
+    }
+
+    /// Compute the bitwise OR of `self` and the specified `BigInt`.
+    pub fn bitor(&self, rhs: &Self) -> Self {
+        let (x, y) = (self.as_host(), rhs.as_host());
+
+        // SAFETY: The host allocation gets cloned to an owned array buffer.
+        let inner = unsafe { (*sys::bigInt::bitOr(&x, &y)).to_array_buffer() }.into_owned();
+
+        Self { inner }
+    }
+
+    /// Compute the bitwise AND of `self` and the specified `BigInt`.
+    pub fn bitand(&self, rhs: &Self) -> Self {
+        let (x, y) = (self.as_host(), rhs.as_host());
+
+        // SAFETY: The host allocation gets cloned to an owned array buffer.
+        let inner = unsafe { (*sys::bigInt::bitAnd(&x, &y)).to_array_buffer() }.into_owned();
+
+        Self { inner }
+    }
+
+    /// Left shift `self` by the specified number of bits.
+    pub fn shl(&self, bits: u8) -> Self {
+        let x = self.as_host();
+
+        // SAFETY: The host allocation gets cloned to an owned array buffer.
+        let inner = unsafe { (*sys::bigInt::leftShift(&x, bits)).to_array_buffer() }.into_owned();
+
+        Self { inner }
+    }
+
+    /// Right shift `self` by the specified number of bits.
+    pub fn shr(&self, bits: u8) -> Self {
         let x = self.as_host();
-        let y = rhs.as_host();
 
         // SAFETY: The host allocation gets cloned to an owned array buffer.
-        let inner = unsafe { sys::bigInt::plus(x, y).to_array_buffer() };
+        let inner = unsafe { (*sys::bigInt::rightShift(&x, bits)).to_array_buffer() }.into_owned();
 
         Self { inner }
     }
@@ -50,7 +146,7 @@ impl BigInt {
     /// - `1` if the number is positive
     /// - `-1` if the number is negative
     pub fn signum(&self) -> i32 {
-        let bytes = self.inner.as_bytes();
+        let bytes = self.inner.as_slice();
 
         // NOTE: In LE, the most significant bit, which contains the sign
         // information is the last byte.
@@ -65,7 +161,21 @@ impl BigInt {
         }
     }
 
-    fn as_host(&self) -> sys::BigInt<'_> {
+    /// Returns the magnitude of `self` as unsigned little-endian bytes.
+    fn magnitude(&self) -> Vec<u8> {
+        let mut bytes = self.inner.as_slice().to_vec();
+        if self.signum() < 0 {
+            let mut carry = 1u16;
+            for byte in &mut bytes {
+                let inverted = u16::from(!*byte) + carry;
+                *byte = inverted as u8;
+                carry = inverted >> 8;
+            }
+        }
+        bytes
+    }
+
+    pub(crate) fn as_host(&self) -> sys::BigInt<'_> {
         sys::BigInt::new(&self.inner)
     }
 }
@@ -80,16 +190,101 @@ impl Display for BigInt {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         let x = self.as_host();
         let s = {
-            let asc_str = unsafe { sys::typeConversion::bigIntToString(x) };
+            let asc_str = unsafe { &*sys::typeConversion::bigIntToString(&x) };
             asc_str
                 .to_string()
                 .expect("integer strings are always valid UTF-16")
         };
 
-        f.pad_integral(self.signum() >= 0, "", &s)
+        // NOTE: `pad_integral` adds its own sign based on `is_nonnegative`, so
+        // strip the one the host already included in the digit string.
+        f.pad_integral(self.signum() >= 0, "", s.strip_prefix('-').unwrap_or(&s))
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let valid = !s.is_empty()
+            && s.bytes()
+                .enumerate()
+                .all(|(i, b)| b.is_ascii_digit() || (i == 0 && (b == b'-' || b == b'+')))
+            && s.bytes().any(|b| b.is_ascii_digit());
+        if !valid {
+            return Err(ParseBigIntError(()));
+        }
+
+        let source = AscString::new(s);
+
+        // SAFETY: The host allocation gets cloned to an owned array buffer.
+        let inner = unsafe {
+            (*sys::bigInt::fromString(source.as_asc_str()))
+                .to_array_buffer()
+                .into_owned()
+        };
+
+        Ok(Self { inner })
+    }
+}
+
+/// An error that can occur parsing a `BigInt` from a string.
+#[derive(Debug, Eq, PartialEq)]
+pub struct ParseBigIntError(());
+
+impl Display for ParseBigIntError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("invalid digit found in string")
+    }
+}
+
+impl std::error::Error for ParseBigIntError {}
+
+impl PartialEq for BigInt {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
     }
 }
 
+impl Eq for BigInt {}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.signum().cmp(&other.signum()) {
+            Ordering::Equal => {}
+            ordering => return ordering,
+        }
+
+        let ordering = compare_magnitude(&self.magnitude(), &other.magnitude());
+        if self.signum() < 0 {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+}
+
+/// Compares two little-endian unsigned magnitudes, ignoring any trailing
+/// (most significant) zero-byte padding.
+fn compare_magnitude(a: &[u8], b: &[u8]) -> Ordering {
+    let len = a.len().max(b.len());
+    for i in (0..len).rev() {
+        let x = a.get(i).copied().unwrap_or(0);
+        let y = b.get(i).copied().unwrap_or(0);
+        match x.cmp(&y) {
+            Ordering::Equal => continue,
+            ordering => return ordering,
+        }
+    }
+    Ordering::Equal
+}
+
 macro_rules! from_primitive {
     ($(
         $m:ident : $($t:ty),* ;
@@ -114,13 +309,13 @@ mod tests {
     #[test]
     fn primitive_conversion() {
         let x = BigInt::from(42u32);
-        assert_eq!(x.inner.as_bytes(), [42, 0, 0, 0]);
+        assert_eq!(x.inner.as_slice(), [42, 0, 0, 0]);
 
         let x = BigInt::from(u32::MAX);
-        assert_eq!(x.inner.as_bytes(), [0xff, 0xff, 0xff, 0xff, 0]);
+        assert_eq!(x.inner.as_slice(), [0xff, 0xff, 0xff, 0xff, 0]);
 
         let x = BigInt::from(-1i32);
-        assert_eq!(x.inner.as_bytes(), [0xff, 0xff, 0xff, 0xff]);
+        assert_eq!(x.inner.as_slice(), [0xff, 0xff, 0xff, 0xff]);
     }
 
     #[test]
@@ -132,21 +327,33 @@ mod tests {
         assert_eq!(BigInt::from(i32::MIN).signum(), -1);
     }
 
-    // TODO(nlordell): This is a useful test, but requires mocking the imported
-    // host functions (specifically `bigIntToString`).
-    /*
+    #[test]
+    fn ordering() {
+        assert!(BigInt::from(-1337) < BigInt::from(-1));
+        assert!(BigInt::from(-1) < BigInt::from(0));
+        assert!(BigInt::from(0) < BigInt::from(42));
+        assert!(BigInt::from(42) < BigInt::from(u32::MAX));
+        assert_eq!(BigInt::from(42), BigInt::from(42));
+    }
+
+    #[test]
+    fn invalid_decimal_string() {
+        assert!("".parse::<BigInt>().is_err());
+        assert!("12a".parse::<BigInt>().is_err());
+        assert!("-".parse::<BigInt>().is_err());
+    }
+
     #[test]
     fn to_string() {
         let pos = BigInt::from(42i32);
         let neg = BigInt::from(-1337i32);
 
         assert_eq!(format!("{}", pos), "42");
-        assert_eq!(format!("{:^8}", pos), "^^^^^^42");
-        assert_eq!(format!("{:-.8}", pos), "42......");
+        assert_eq!(format!("{:^>8}", pos), "^^^^^^42");
+        assert_eq!(format!("{:.<8}", pos), "42......");
 
         assert_eq!(format!("{}", neg), "-1337");
-        assert_eq!(format!("{:^8}", neg), "^^^-1337");
-        assert_eq!(format!("{:-.8}", neg), "-1337...");
+        assert_eq!(format!("{:^>8}", neg), "^^^-1337");
+        assert_eq!(format!("{:.<8}", neg), "-1337...");
     }
-    */
 }
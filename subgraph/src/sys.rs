@@ -0,0 +1,19 @@
+//! Host import function bindings.
+
+#[cfg(target_arch = "wasm32")]
+#[path = "sys/host.rs"]
+mod bindings;
+
+#[cfg(not(target_arch = "wasm32"))]
+#[path = "sys/mock.rs"]
+mod bindings;
+
+pub use self::bindings::*;
+use crate::ffi::array::AscUint8Array;
+
+/// The host `BigInt` type.
+pub type BigInt<'a> = AscUint8Array<'a>;
+
+/// The host `BigDecimal` type. Represented the same way as `BigInt` on the
+/// host: as an array buffer of little-endian bytes.
+pub type BigDecimal<'a> = AscUint8Array<'a>;
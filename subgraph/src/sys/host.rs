@@ -2,8 +2,8 @@
 
 #![allow(non_snake_case)]
 
-use super::BigInt;
-use crate::ffi::string::AscStr;
+use super::{BigDecimal, BigInt};
+use crate::ffi::{array::AscArrayBuf, string::AscStr};
 
 #[link(wasm_import_module = "env")]
 extern "C" {
@@ -18,6 +18,61 @@ pub mod bigInt {
     extern "C" {
         #[link_name = "bigInt.plus"]
         pub fn plus(x: &BigInt, y: &BigInt) -> *mut BigInt<'static>;
+
+        #[link_name = "bigInt.minus"]
+        pub fn minus(x: &BigInt, y: &BigInt) -> *mut BigInt<'static>;
+
+        #[link_name = "bigInt.times"]
+        pub fn times(x: &BigInt, y: &BigInt) -> *mut BigInt<'static>;
+
+        #[link_name = "bigInt.dividedBy"]
+        pub fn dividedBy(x: &BigInt, y: &BigInt) -> *mut BigInt<'static>;
+
+        #[link_name = "bigInt.mod"]
+        pub fn r#mod(x: &BigInt, y: &BigInt) -> *mut BigInt<'static>;
+
+        #[link_name = "bigInt.pow"]
+        pub fn pow(x: &BigInt, exp: u8) -> *mut BigInt<'static>;
+
+        #[link_name = "bigInt.bitOr"]
+        pub fn bitOr(x: &BigInt, y: &BigInt) -> *mut BigInt<'static>;
+
+        #[link_name = "bigInt.bitAnd"]
+        pub fn bitAnd(x: &BigInt, y: &BigInt) -> *mut BigInt<'static>;
+
+        #[link_name = "bigInt.leftShift"]
+        pub fn leftShift(x: &BigInt, bits: u8) -> *mut BigInt<'static>;
+
+        #[link_name = "bigInt.rightShift"]
+        pub fn rightShift(x: &BigInt, bits: u8) -> *mut BigInt<'static>;
+
+        #[link_name = "bigInt.fromString"]
+        pub fn fromString(s: &AscStr) -> *mut BigInt<'static>;
+    }
+}
+
+pub mod bigDecimal {
+    use super::*;
+
+    #[link(wasm_import_module = "index")]
+    extern "C" {
+        #[link_name = "bigDecimal.plus"]
+        pub fn plus(x: &BigDecimal, y: &BigDecimal) -> *mut BigDecimal<'static>;
+
+        #[link_name = "bigDecimal.minus"]
+        pub fn minus(x: &BigDecimal, y: &BigDecimal) -> *mut BigDecimal<'static>;
+
+        #[link_name = "bigDecimal.times"]
+        pub fn times(x: &BigDecimal, y: &BigDecimal) -> *mut BigDecimal<'static>;
+
+        #[link_name = "bigDecimal.dividedBy"]
+        pub fn dividedBy(x: &BigDecimal, y: &BigDecimal) -> *mut BigDecimal<'static>;
+
+        #[link_name = "bigDecimal.toString"]
+        pub fn toString(x: &BigDecimal) -> *mut AscStr;
+
+        #[link_name = "bigDecimal.fromString"]
+        pub fn fromString(s: &AscStr) -> *mut BigDecimal<'static>;
     }
 }
 
@@ -38,5 +93,128 @@ pub mod typeConversion {
     extern "C" {
         #[link_name = "typeConversion.bigIntToString"]
         pub fn bigIntToString(x: &BigInt) -> *mut AscStr;
+
+        #[link_name = "typeConversion.bigIntToHex"]
+        pub fn bigIntToHex(x: &BigInt) -> *mut AscStr;
+
+        #[link_name = "typeConversion.i32ToBigInt"]
+        pub fn i32ToBigInt(x: i32) -> *mut BigInt<'static>;
+
+        #[link_name = "typeConversion.bigIntToI32"]
+        pub fn bigIntToI32(x: &BigInt) -> i32;
+
+        #[link_name = "typeConversion.bytesToHex"]
+        pub fn bytesToHex(bytes: &AscArrayBuf) -> *mut AscStr;
+
+        #[link_name = "typeConversion.bytesToString"]
+        pub fn bytesToString(bytes: &AscArrayBuf) -> *mut AscStr;
+
+        #[link_name = "typeConversion.bytesToBase58"]
+        pub fn bytesToBase58(bytes: &AscArrayBuf) -> *mut AscStr;
+
+        #[link_name = "typeConversion.stringToH160"]
+        pub fn stringToH160(s: &AscStr) -> *mut AscArrayBuf;
+    }
+}
+
+pub mod ethereum {
+    use super::*;
+
+    #[link(wasm_import_module = "index")]
+    extern "C" {
+        /// Invokes a smart contract call on the host. `call` is a host-defined
+        /// encoding of the contract address, ABI, function signature and
+        /// parameters; the result is the host's encoding of the returned
+        /// values (or a null pointer on revert).
+        #[link_name = "ethereum.call"]
+        pub fn call(call: &AscArrayBuf) -> *mut AscArrayBuf;
+    }
+}
+
+pub mod store {
+    use super::*;
+
+    #[link(wasm_import_module = "index")]
+    extern "C" {
+        /// Loads an entity by type and id, returning the host's encoding of
+        /// its field values, or a null pointer if no such entity exists.
+        #[link_name = "store.get"]
+        pub fn get(entity: &AscStr, id: &AscStr) -> *mut AscArrayBuf;
+
+        /// Sets the field values of an entity by type and id, using the same
+        /// encoding returned by [`get`].
+        #[link_name = "store.set"]
+        pub fn set(entity: &AscStr, id: &AscStr, data: &AscArrayBuf);
+
+        /// Removes an entity by type and id.
+        #[link_name = "store.remove"]
+        pub fn remove(entity: &AscStr, id: &AscStr);
+    }
+}
+
+pub mod ipfs {
+    use super::*;
+
+    #[link(wasm_import_module = "index")]
+    extern "C" {
+        /// Reads the complete contents of the file behind an IPFS hash or
+        /// path.
+        #[link_name = "ipfs.cat"]
+        pub fn cat(hash: &AscStr) -> *mut AscArrayBuf;
+
+        /// Streams newline-delimited JSON values from the file behind an IPFS
+        /// hash or path to a mapping callback. `user_data` and `flags` are
+        /// host-defined encodings of, respectively, extra context passed
+        /// through to the callback and processing flags.
+        #[link_name = "ipfs.map"]
+        pub fn map(hash: &AscStr, callback: &AscStr, user_data: &AscArrayBuf, flags: &AscArrayBuf);
+    }
+}
+
+pub mod json {
+    use super::*;
+
+    #[link(wasm_import_module = "index")]
+    extern "C" {
+        /// Parses a UTF-8 encoded byte buffer as JSON, returning the host's
+        /// encoding of the resulting value.
+        #[link_name = "json.fromBytes"]
+        pub fn fromBytes(data: &AscArrayBuf) -> *mut AscArrayBuf;
+
+        /// Converts a host-encoded JSON value into a `BigInt`.
+        #[link_name = "json.toBigInt"]
+        pub fn toBigInt(value: &AscArrayBuf) -> *mut BigInt<'static>;
+
+        /// Converts a host-encoded JSON value into an `i64`.
+        #[link_name = "json.toI64"]
+        pub fn toI64(value: &AscArrayBuf) -> i64;
+    }
+}
+
+pub mod crypto {
+    use super::*;
+
+    #[link(wasm_import_module = "index")]
+    extern "C" {
+        /// Hashes a byte buffer using `keccak256`.
+        #[link_name = "crypto.keccak256"]
+        pub fn keccak256(data: &AscArrayBuf) -> *mut AscArrayBuf;
+    }
+}
+
+pub mod dataSource {
+    use super::*;
+
+    #[link(wasm_import_module = "index")]
+    extern "C" {
+        /// Returns the address of the contract that the current data source
+        /// is indexing.
+        #[link_name = "dataSource.address"]
+        pub fn address() -> *mut AscArrayBuf;
+
+        /// Returns the host's encoding of the current data source's context,
+        /// or a null pointer if it has none.
+        #[link_name = "dataSource.context"]
+        pub fn context() -> *mut AscArrayBuf;
     }
 }
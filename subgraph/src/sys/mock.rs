@@ -2,8 +2,11 @@
 
 #![allow(non_snake_case)]
 
-use super::BigInt;
-use crate::ffi::string::AscStr;
+use super::{BigDecimal, BigInt};
+use crate::ffi::{
+    array::AscArrayBuf,
+    string::{AscStr, AscString},
+};
 
 pub unsafe fn abort(_: &AscStr, _: Option<&AscStr>, _: u32, _: u32) -> ! {
     unreachable!("mocked abort host method called");
@@ -15,6 +18,74 @@ pub mod bigInt {
     pub unsafe fn plus(_x: &BigInt, _y: &BigInt) -> *mut BigInt<'static> {
         todo!()
     }
+
+    pub unsafe fn minus(_x: &BigInt, _y: &BigInt) -> *mut BigInt<'static> {
+        todo!()
+    }
+
+    pub unsafe fn times(_x: &BigInt, _y: &BigInt) -> *mut BigInt<'static> {
+        todo!()
+    }
+
+    pub unsafe fn dividedBy(_x: &BigInt, _y: &BigInt) -> *mut BigInt<'static> {
+        todo!()
+    }
+
+    pub unsafe fn r#mod(_x: &BigInt, _y: &BigInt) -> *mut BigInt<'static> {
+        todo!()
+    }
+
+    pub unsafe fn pow(_x: &BigInt, _exp: u8) -> *mut BigInt<'static> {
+        todo!()
+    }
+
+    pub unsafe fn bitOr(_x: &BigInt, _y: &BigInt) -> *mut BigInt<'static> {
+        todo!()
+    }
+
+    pub unsafe fn bitAnd(_x: &BigInt, _y: &BigInt) -> *mut BigInt<'static> {
+        todo!()
+    }
+
+    pub unsafe fn leftShift(_x: &BigInt, _bits: u8) -> *mut BigInt<'static> {
+        todo!()
+    }
+
+    pub unsafe fn rightShift(_x: &BigInt, _bits: u8) -> *mut BigInt<'static> {
+        todo!()
+    }
+
+    pub unsafe fn fromString(_s: &AscStr) -> *mut BigInt<'static> {
+        todo!()
+    }
+}
+
+pub mod bigDecimal {
+    use super::*;
+
+    pub unsafe fn plus(_x: &BigDecimal, _y: &BigDecimal) -> *mut BigDecimal<'static> {
+        todo!()
+    }
+
+    pub unsafe fn minus(_x: &BigDecimal, _y: &BigDecimal) -> *mut BigDecimal<'static> {
+        todo!()
+    }
+
+    pub unsafe fn times(_x: &BigDecimal, _y: &BigDecimal) -> *mut BigDecimal<'static> {
+        todo!()
+    }
+
+    pub unsafe fn dividedBy(_x: &BigDecimal, _y: &BigDecimal) -> *mut BigDecimal<'static> {
+        todo!()
+    }
+
+    pub unsafe fn toString(_x: &BigDecimal) -> *mut AscStr {
+        todo!()
+    }
+
+    pub unsafe fn fromString(_s: &AscStr) -> *mut BigDecimal<'static> {
+        todo!()
+    }
 }
 
 pub mod log {
@@ -28,7 +99,163 @@ pub mod log {
 pub mod typeConversion {
     use super::*;
 
-    pub unsafe fn bigIntToString(_x: BigInt) -> *mut AscStr {
+    pub unsafe fn bigIntToString(x: &BigInt) -> *mut AscStr {
+        let string = AscString::new(decimal_string(x.as_bytes()));
+        let leaked: &'static AscString = Box::leak(Box::new(string));
+
+        leaked.as_asc_str() as *const AscStr as *mut AscStr
+    }
+
+    pub unsafe fn bigIntToHex(_x: &BigInt) -> *mut AscStr {
+        todo!()
+    }
+
+    pub unsafe fn i32ToBigInt(_x: i32) -> *mut BigInt<'static> {
+        todo!()
+    }
+
+    pub unsafe fn bigIntToI32(_x: &BigInt) -> i32 {
         todo!()
     }
+
+    pub unsafe fn bytesToHex(_bytes: &AscArrayBuf) -> *mut AscStr {
+        todo!()
+    }
+
+    pub unsafe fn bytesToString(_bytes: &AscArrayBuf) -> *mut AscStr {
+        todo!()
+    }
+
+    pub unsafe fn bytesToBase58(_bytes: &AscArrayBuf) -> *mut AscStr {
+        todo!()
+    }
+
+    pub unsafe fn stringToH160(_s: &AscStr) -> *mut AscArrayBuf {
+        todo!()
+    }
+}
+
+pub mod ethereum {
+    use super::*;
+
+    pub unsafe fn call(_call: &AscArrayBuf) -> *mut AscArrayBuf {
+        unreachable!("mocked ethereum.call host method called");
+    }
+}
+
+pub mod store {
+    use super::*;
+
+    pub unsafe fn get(_entity: &AscStr, _id: &AscStr) -> *mut AscArrayBuf {
+        unreachable!("mocked store.get host method called");
+    }
+
+    pub unsafe fn set(_entity: &AscStr, _id: &AscStr, _data: &AscArrayBuf) {
+        unreachable!("mocked store.set host method called");
+    }
+
+    pub unsafe fn remove(_entity: &AscStr, _id: &AscStr) {
+        unreachable!("mocked store.remove host method called");
+    }
+}
+
+pub mod ipfs {
+    use super::*;
+
+    pub unsafe fn cat(_hash: &AscStr) -> *mut AscArrayBuf {
+        unreachable!("mocked ipfs.cat host method called");
+    }
+
+    pub unsafe fn map(
+        _hash: &AscStr,
+        _callback: &AscStr,
+        _user_data: &AscArrayBuf,
+        _flags: &AscArrayBuf,
+    ) {
+        unreachable!("mocked ipfs.map host method called");
+    }
+}
+
+pub mod json {
+    use super::*;
+
+    pub unsafe fn fromBytes(_data: &AscArrayBuf) -> *mut AscArrayBuf {
+        unreachable!("mocked json.fromBytes host method called");
+    }
+
+    pub unsafe fn toBigInt(_value: &AscArrayBuf) -> *mut BigInt<'static> {
+        unreachable!("mocked json.toBigInt host method called");
+    }
+
+    pub unsafe fn toI64(_value: &AscArrayBuf) -> i64 {
+        unreachable!("mocked json.toI64 host method called");
+    }
+}
+
+pub mod crypto {
+    use super::*;
+
+    pub unsafe fn keccak256(_data: &AscArrayBuf) -> *mut AscArrayBuf {
+        unreachable!("mocked crypto.keccak256 host method called");
+    }
+}
+
+pub mod dataSource {
+    use super::*;
+
+    pub unsafe fn address() -> *mut AscArrayBuf {
+        unreachable!("mocked dataSource.address host method called");
+    }
+
+    pub unsafe fn context() -> *mut AscArrayBuf {
+        unreachable!("mocked dataSource.context host method called");
+    }
+}
+
+/// Converts little-endian two's-complement bytes into a decimal string, using
+/// schoolbook long division by 10.
+fn decimal_string(bytes: &[u8]) -> String {
+    let negative = matches!(bytes.last(), Some(byte) if byte & 0x80 != 0);
+    let mut magnitude = bytes.to_vec();
+    if negative {
+        let mut carry = 1u16;
+        for byte in &mut magnitude {
+            let inverted = u16::from(!*byte) + carry;
+            *byte = inverted as u8;
+            carry = inverted >> 8;
+        }
+    }
+
+    let mut digits = Vec::new();
+    while magnitude.iter().any(|&byte| byte != 0) {
+        let mut remainder = 0u32;
+        for byte in magnitude.iter_mut().rev() {
+            let value = (remainder << 8) | u32::from(*byte);
+            *byte = (value / 10) as u8;
+            remainder = value % 10;
+        }
+        digits.push(b'0' + remainder as u8);
+    }
+    if digits.is_empty() {
+        digits.push(b'0');
+    }
+    if negative {
+        digits.push(b'-');
+    }
+    digits.reverse();
+
+    String::from_utf8(digits).expect("only ever contains ASCII digits and a sign")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decimal_string_conversion() {
+        assert_eq!(decimal_string(&[0]), "0");
+        assert_eq!(decimal_string(&[42, 0, 0, 0]), "42");
+        assert_eq!(decimal_string(&[0xff, 0xff, 0xff, 0xff]), "-1");
+        assert_eq!(decimal_string(&[0xc7, 0xfa, 0xff, 0xff]), "-1337");
+    }
 }